@@ -0,0 +1,210 @@
+//! Builds the URLs of images hosted on Discord's CDN, like avatars and guild icons.
+//!
+//! [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/reference#image-formatting)
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use super::resources::{Emoji, Guild, User};
+
+/// The base URL of Discord's CDN.
+pub const BASE_URL: &str = "https://cdn.discordapp.com";
+
+/// The file format of an image.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageFormat {
+    /// PNG, the default for images that aren't animated.
+    Png,
+    /// JPEG.
+    Jpeg,
+    /// WebP.
+    WebP,
+    /// GIF, only available for animated images and the default for them.
+    Gif,
+}
+
+impl ImageFormat {
+    /// The file extension used for this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::WebP => "webp",
+            ImageFormat::Gif => "gif",
+        }
+    }
+}
+
+/// Why an image URL could not be built.
+#[derive(Debug, PartialEq)]
+pub enum CdnError {
+    /// The size was not a power of two between 16 and 4096.
+    InvalidSize(u16),
+    /// The image is not available in the format, e.g. a GIF of an image that isn't animated.
+    UnsupportedFormat(ImageFormat),
+}
+
+impl Display for CdnError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            CdnError::InvalidSize(size) => write!(f, "{} is not a power of two between 16 and 4096", size),
+            CdnError::UnsupportedFormat(format) => write!(f, "the image is not available as a {}", format.extension()),
+        }
+    }
+}
+
+impl Error for CdnError {}
+
+/// The URL of an image, with the format and size still to be chosen.
+pub struct ImageUrl {
+    path: String,
+    animated: bool,
+    formats: &'static [ImageFormat],
+    format: Option<ImageFormat>,
+    size: Option<u16>,
+}
+
+const STATIC_FORMATS: &[ImageFormat] = &[ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::WebP];
+const ANIMATED_FORMATS: &[ImageFormat] = &[ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::WebP, ImageFormat::Gif];
+
+impl ImageUrl {
+    fn new(path: String, animated: bool) -> Self {
+        Self {
+            path,
+            animated,
+            formats: if animated { ANIMATED_FORMATS } else { STATIC_FORMATS },
+            format: None,
+            size: None,
+        }
+    }
+
+    fn hashed(path: &str, hash: &str) -> Self {
+        Self::new(format!("{}/{}", path, hash), hash.starts_with("a_"))
+    }
+
+    /// Sets the format, otherwise GIF is used for animated images and PNG for everything else.
+    pub fn format(mut self, format: ImageFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the size of the image, which has to be a power of two between 16 and 4096.
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Checks the format and size and returns the URL.
+    pub fn build(self) -> Result<String, CdnError> {
+        let default = if self.animated { ImageFormat::Gif } else { ImageFormat::Png };
+        let format = self.format.unwrap_or(default);
+        if !self.formats.contains(&format) {
+            return Err(CdnError::UnsupportedFormat(format));
+        }
+        let mut url = format!("{}/{}.{}", BASE_URL, self.path, format.extension());
+        if let Some(size) = self.size {
+            if !size.is_power_of_two() || !(16..=4096).contains(&size) {
+                return Err(CdnError::InvalidSize(size));
+            }
+            url.push_str(&format!("?size={}", size));
+        }
+        Ok(url)
+    }
+}
+
+/// The user's avatar, or their default avatar if they haven't set one.
+pub fn user_avatar(user: &User) -> ImageUrl {
+    match &user.avatar {
+        Some(hash) => ImageUrl::hashed(&format!("avatars/{}", user.id), hash),
+        None => default_avatar(user),
+    }
+}
+
+/// The avatar a user has when they haven't uploaded one, which is only available as a PNG.
+///
+/// This is picked using the discriminator, or the user's id if they have moved to the new username system.
+pub fn default_avatar(user: &User) -> ImageUrl {
+    let index = match user.discriminator.parse::<u16>() {
+        Ok(0) | Err(_) => ((user.id.0).0 >> 22) % 6,
+        Ok(discriminator) => u64::from(discriminator % 5),
+    };
+    let mut url = ImageUrl::new(format!("embed/avatars/{}", index), false);
+    url.formats = &[ImageFormat::Png];
+    url
+}
+
+/// The guild's icon, if it has one.
+pub fn guild_icon(guild: &Guild) -> Option<ImageUrl> {
+    guild.icon.as_ref().map(|hash| ImageUrl::hashed(&format!("icons/{}", guild.id), hash))
+}
+
+/// The guild's invite splash, if it has one.
+pub fn guild_splash(guild: &Guild) -> Option<ImageUrl> {
+    guild.splash.as_ref().map(|hash| ImageUrl::hashed(&format!("splashes/{}", guild.id), hash))
+}
+
+/// The guild's banner, if it has one.
+pub fn guild_banner(guild: &Guild) -> Option<ImageUrl> {
+    guild.banner.as_ref().map(|hash| ImageUrl::hashed(&format!("banners/{}", guild.id), hash))
+}
+
+/// The image of a custom emoji.
+pub fn emoji(emoji: &Emoji) -> ImageUrl {
+    ImageUrl::new(format!("emojis/{}", emoji.id), emoji.animated.unwrap_or(false))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+
+    fn user(avatar: Option<&str>, discriminator: &str) -> User {
+        from_value(json!({
+            "id": "175928847299117063", "username": "user", "discriminator": discriminator, "avatar": avatar,
+        })).unwrap()
+    }
+
+    #[test]
+    fn animated_hash_defaults_to_gif() {
+        let animated = user(Some("a_1269e74af4df7417b13759eae50c83dc"), "0001");
+        assert_eq!(
+            user_avatar(&animated).build().unwrap(),
+            "https://cdn.discordapp.com/avatars/175928847299117063/a_1269e74af4df7417b13759eae50c83dc.gif",
+        );
+        assert_eq!(
+            user_avatar(&animated).format(ImageFormat::WebP).build().unwrap(),
+            "https://cdn.discordapp.com/avatars/175928847299117063/a_1269e74af4df7417b13759eae50c83dc.webp",
+        );
+        let still = user(Some("1269e74af4df7417b13759eae50c83dc"), "0001");
+        assert_eq!(
+            user_avatar(&still).build().unwrap(),
+            "https://cdn.discordapp.com/avatars/175928847299117063/1269e74af4df7417b13759eae50c83dc.png",
+        );
+        assert_eq!(user_avatar(&still).format(ImageFormat::Gif).build(), Err(CdnError::UnsupportedFormat(ImageFormat::Gif)));
+    }
+
+    #[test]
+    fn size() {
+        let url = |size| user_avatar(&user(Some("hash"), "0001")).size(size).build();
+        assert_eq!(url(16).unwrap(), "https://cdn.discordapp.com/avatars/175928847299117063/hash.png?size=16");
+        assert!(url(4096).is_ok());
+        for size in [0, 8, 100, 8192] {
+            assert_eq!(url(size), Err(CdnError::InvalidSize(size)));
+        }
+    }
+
+    #[test]
+    fn default_avatar_index() {
+        assert_eq!(user_avatar(&user(None, "1337")).build().unwrap(), "https://cdn.discordapp.com/embed/avatars/2.png");
+        assert_eq!(user_avatar(&user(None, "0001")).build().unwrap(), "https://cdn.discordapp.com/embed/avatars/1.png");
+        assert_eq!(user_avatar(&user(None, "0")).build().unwrap(), "https://cdn.discordapp.com/embed/avatars/2.png");
+        assert_eq!(default_avatar(&user(None, "0")).format(ImageFormat::Jpeg).build(), Err(CdnError::UnsupportedFormat(ImageFormat::Jpeg)));
+    }
+
+    #[test]
+    fn emoji_url() {
+        let emoji: Emoji = from_value(json!({ "id": "41771983429993937", "name": "LUL", "animated": true })).unwrap();
+        assert_eq!(super::emoji(&emoji).size(64).build().unwrap(), "https://cdn.discordapp.com/emojis/41771983429993937.gif?size=64");
+    }
+}
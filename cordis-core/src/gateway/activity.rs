@@ -0,0 +1,308 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::ValidationError;
+use super::super::resources::{ApplicationId, ReactionType};
+
+/// What a user is doing, shown in their profile.
+///
+/// Bots can only set `name`, `type`, `url` and, for custom statuses, `state`.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/topics/gateway#activity-object)
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Activity {
+    /// The activity's name.
+    pub name: String,
+    /// Activity Type.
+    #[serde(rename = "type")]
+    pub activity_type: ActivityType,
+    /// Stream URL.
+    ///
+    /// Is validated when type is 1.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// When the activity was added to the user's session, in Unix time (milliseconds).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<u64>,
+    /// When the activity started and ends.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamps: Option<ActivityTimestamps>,
+    /// The id of the application of the game.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<ApplicationId>,
+    /// What the user is doing, e.g. the song playing on Spotify.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+    /// The state of the party, or the text of a custom status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// The emoji of a custom status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ReactionType>,
+    /// The party the user is in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub party: Option<ActivityParty>,
+    /// Images shown with the activity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assets: Option<ActivityAssets>,
+    /// Secrets used to join or spectate the game.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<ActivitySecrets>,
+    /// Whether the activity is an instanced game session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<bool>,
+    /// What the activity payload includes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flags: Option<ActivityFlags>,
+}
+
+impl Activity {
+    /// "Playing {name}".
+    pub fn playing(name: impl Into<String>) -> Self {
+        Self { name: name.into(), activity_type: ActivityType::Game, ..Default::default() }
+    }
+
+    /// "Streaming {name}", the url has to be a Twitch or YouTube URL.
+    pub fn streaming(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self { name: name.into(), activity_type: ActivityType::Streaming, url: Some(url.into()), ..Default::default() }
+    }
+
+    /// "Listening to {name}".
+    pub fn listening(name: impl Into<String>) -> Self {
+        Self { name: name.into(), activity_type: ActivityType::Listening, ..Default::default() }
+    }
+
+    /// "Watching {name}".
+    pub fn watching(name: impl Into<String>) -> Self {
+        Self { name: name.into(), activity_type: ActivityType::Watching, ..Default::default() }
+    }
+
+    /// "Competing in {name}".
+    pub fn competing(name: impl Into<String>) -> Self {
+        Self { name: name.into(), activity_type: ActivityType::Competing, ..Default::default() }
+    }
+
+    /// A custom status showing the text.
+    pub fn custom(state: impl Into<String>) -> Self {
+        Self {
+            name: "Custom Status".to_string(),
+            activity_type: ActivityType::Custom,
+            state: Some(state.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Checks that a `Streaming` activity has a Twitch or YouTube URL.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match (&self.activity_type, &self.url) {
+            (ActivityType::Streaming, Some(url)) if is_stream_url(url) => Ok(()),
+            (ActivityType::Streaming, url) => Err(ValidationError::StreamingUrl(url.clone())),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn is_stream_url(url: &str) -> bool {
+    let rest = match url.find("://") {
+        Some(index) if matches!(&url[..index], "http" | "https") => &url[index + 3..],
+        _ => return false,
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = host.strip_prefix("www.").unwrap_or(host);
+    matches!(host, "twitch.tv" | "youtube.com")
+}
+
+/// The type of activity the user is doing.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum ActivityType {
+    /// Playing {name}.
+    ///
+    /// e.g. "Playing Rocket League"
+    #[default]
+    Game,
+    /// Streaming {name}.
+    ///
+    /// e.g. "Streaming Rocker League"
+    Streaming,
+    /// Listening to {name}.
+    ///
+    /// e.g. "Listening to Spotify"
+    Listening,
+    /// Watching {name}.
+    ///
+    /// e.g. "Watching YouTube Together"
+    Watching,
+    /// {emoji} {state}.
+    ///
+    /// e.g. ":smiley: I am cool"
+    Custom,
+    /// Competing in {name}.
+    ///
+    /// e.g. "Competing in Arena World Champions"
+    Competing,
+    /// A type of activity that isn't supported yet.
+    Unknown(u8),
+}
+
+impl From<u8> for ActivityType {
+    fn from(code: u8) -> Self {
+        match code {
+            0 => ActivityType::Game,
+            1 => ActivityType::Streaming,
+            2 => ActivityType::Listening,
+            3 => ActivityType::Watching,
+            4 => ActivityType::Custom,
+            5 => ActivityType::Competing,
+            code => ActivityType::Unknown(code),
+        }
+    }
+}
+
+impl From<ActivityType> for u8 {
+    fn from(kind: ActivityType) -> Self {
+        match kind {
+            ActivityType::Game => 0,
+            ActivityType::Streaming => 1,
+            ActivityType::Listening => 2,
+            ActivityType::Watching => 3,
+            ActivityType::Custom => 4,
+            ActivityType::Competing => 5,
+            ActivityType::Unknown(code) => code,
+        }
+    }
+}
+
+/// When an activity started and ends, in Unix time (milliseconds).
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ActivityTimestamps {
+    /// When the activity started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<u64>,
+    /// When the activity ends.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<u64>,
+}
+
+/// The party a user is in.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ActivityParty {
+    /// The id of the party.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The current and maximum size of the party.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<[u32; 2]>,
+}
+
+/// Images shown with an activity, with the text shown when hovering over them.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ActivityAssets {
+    /// The id or URL of the large image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub large_image: Option<String>,
+    /// The text shown when hovering over the large image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub large_text: Option<String>,
+    /// The id or URL of the small image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub small_image: Option<String>,
+    /// The text shown when hovering over the small image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub small_text: Option<String>,
+}
+
+/// Secrets used to join or spectate a game.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ActivitySecrets {
+    /// The secret for joining the party.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub join: Option<String>,
+    /// The secret for spectating the game.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spectate: Option<String>,
+    /// The secret for an instanced match.
+    #[serde(default, rename = "match", skip_serializing_if = "Option::is_none")]
+    pub match_: Option<String>,
+}
+
+bitflags! {
+    /// What an activity payload includes.
+    ///
+    /// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/topics/gateway#activity-object-activity-flags)
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+    pub struct ActivityFlags: u32 {
+        /// The activity is an instanced game session.
+        const INSTANCE = 1 << 0;
+        /// The party can be joined.
+        const JOIN = 1 << 1;
+        /// The game can be spectated.
+        const SPECTATE = 1 << 2;
+        /// Users can ask to join the party.
+        const JOIN_REQUEST = 1 << 3;
+        /// The activity is synced, like a song on Spotify.
+        const SYNC = 1 << 4;
+        /// The activity can be played along with.
+        const PLAY = 1 << 5;
+        /// Only friends can join the party.
+        const PARTY_PRIVACY_FRIENDS = 1 << 6;
+        /// Only users in the same voice channel can join the party.
+        const PARTY_PRIVACY_VOICE_CHANNEL = 1 << 7;
+        /// The activity is an app running in a voice channel.
+        const EMBEDDED = 1 << 8;
+    }
+}
+
+impl Serialize for ActivityFlags {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_u32(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for ActivityFlags {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        u32::deserialize(d).map(ActivityFlags::from_bits_retain)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+
+    #[test]
+    fn spotify() {
+        let activity: Activity = from_value(json!({
+            "type": 2, "name": "Spotify", "id": "spotify:1", "created_at": 1_625_097_600_000u64,
+            "timestamps": { "start": 1_625_097_590_000u64, "end": 1_625_097_800_000u64 },
+            "details": "Song", "state": "Artist", "sync_id": "4cOdK2wGLETKBW3PvgPWqT", "session_id": "abc",
+            "party": { "id": "spotify:100" },
+            "assets": { "large_image": "spotify:ab67616d0000b273", "large_text": "Album" },
+            "flags": 48,
+        })).unwrap();
+        assert_eq!(activity.activity_type, ActivityType::Listening);
+        assert_eq!(activity.timestamps, Some(ActivityTimestamps { start: Some(1_625_097_590_000), end: Some(1_625_097_800_000) }));
+        assert_eq!(activity.party, Some(ActivityParty { id: Some("spotify:100".to_string()), size: None }));
+        assert_eq!(activity.assets.as_ref().and_then(|assets| assets.large_text.as_deref()), Some("Album"));
+        assert_eq!(activity.flags, Some(ActivityFlags::SYNC | ActivityFlags::PLAY));
+    }
+
+    #[test]
+    fn custom_status() {
+        let activity: Activity = from_value(json!({
+            "type": 4, "name": "Custom Status", "state": "I am cool", "emoji": { "name": "😀" }, "created_at": 1,
+        })).unwrap();
+        assert_eq!(activity.activity_type, ActivityType::Custom);
+        assert_eq!(activity.emoji, Some(ReactionType::Unicode("😀".to_string())));
+        assert_eq!(activity.state.as_deref(), Some("I am cool"));
+    }
+
+    #[test]
+    fn unknown_type() {
+        let activity: Activity = from_value(json!({ "type": 7, "name": "Something new", "flags": 1024 })).unwrap();
+        assert_eq!(activity.activity_type, ActivityType::Unknown(7));
+        assert_eq!(activity.flags.map(|flags| flags.bits()), Some(1024));
+        assert_eq!(serde_json::to_value(&activity).unwrap(), json!({ "type": 7, "name": "Something new", "flags": 1024 }));
+    }
+}
@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 use super::super::{Activity, Status};
-use super::super::super::resources::{Channel, ChannelId, Guild, GuildMember, RoleId, PremiumType, UserId};
+use super::super::super::resources::{Channel, ChannelId, Guild, GuildMember, GuildScheduledEvent, RoleId, PremiumType, StageInstance, UserId};
 
 /// A guild that was either created or lazily-loaded.
 #[derive(Deserialize)]
@@ -24,8 +24,17 @@ pub struct GuildCreate {
     pub members: Vec<GuildMember>,
     /// Channels in the guild.
     pub channels: Vec<Channel>,
+    /// Active threads in the guild that the current user can see.
+    #[serde(default)]
+    pub threads: Vec<Channel>,
     /// Presences of the users in the guild.
     pub presences: Vec<PartialPresenceUpdate>,
+    /// Stages that are live in the guild.
+    #[serde(default)]
+    pub stage_instances: Vec<StageInstance>,
+    /// Events scheduled in the guild.
+    #[serde(default)]
+    pub guild_scheduled_events: Vec<GuildScheduledEvent>,
 }
 
 /// A voice state of a member of a guild.
@@ -60,11 +69,12 @@ pub struct PartialPresenceUpdate {
     pub user: PartialUser,
     /// Roles the user is in.
     pub roles: Vec<RoleId>,
-    /// The user's current activity.
+    /// The user's current activity, which is the first of `activities`.
     pub game: Option<Activity>,
     /// The status of the user.
     pub status: Status,
     /// User's current activities.
+    #[serde(default)]
     pub activities: Vec<Activity>,
     /// User's platform-dependant status.
     pub client_status: ClientStatus,
@@ -116,4 +126,115 @@ pub enum ClientSessionStatus {
     Online,
     Idle,
     Dnd,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json, Value};
+
+    use super::*;
+    use super::super::super::super::resources::{
+        channel::ChannelType, PrivacyLevel, ScheduledEventEntityType, ScheduledEventStatus, Snowflake,
+    };
+
+    fn guild_create(extra: Value) -> GuildCreate {
+        let mut guild = json!({
+            "id": "1", "name": "guild", "icon": null, "splash": null, "owner": false,
+            "owner_id": "100", "permissions": 0, "region": "sydney", "afk_timeout": 300,
+            "verification_level": 0, "default_message_notifications": 0, "explicit_content_filter": 0,
+            "roles": [], "emojis": [], "features": [], "mfa_level": 0, "premium_tier": 0, "preferred_locale": "en-US",
+            "joined_at": "2021-07-01T00:00:00Z", "large": false, "unavailable": false, "member_count": 2,
+            "voice_states": [], "members": [], "channels": [], "presences": [],
+        });
+        for (key, value) in extra.as_object().unwrap() {
+            guild[key] = value.clone();
+        }
+        from_value(guild).unwrap()
+    }
+
+    #[test]
+    fn threads_and_newer_channels() {
+        let event = guild_create(json!({
+            "channels": [
+                {
+                    "id": "10", "type": 13, "guild_id": "1", "name": "Town Hall", "position": 0, "bitrate": 64000,
+                    "topic": null, "permission_overwrites": [], "parent_id": null,
+                },
+                { "id": "11", "type": 15, "guild_id": "1", "name": "help", "position": 1, "available_tags": [] },
+                { "id": "12", "type": 99, "guild_id": "1", "name": "future", "position": 2 },
+            ],
+            "threads": [{
+                "id": "20", "type": 11, "guild_id": "1", "parent_id": "30", "owner_id": "100", "name": "Release notes",
+                "last_message_id": "21", "rate_limit_per_user": 0, "message_count": 3, "member_count": 2,
+                "thread_metadata": {
+                    "archived": false, "auto_archive_duration": 1440, "archive_timestamp": "2021-07-01T00:00:00Z",
+                    "locked": false, "create_timestamp": "2021-07-01T00:00:00Z",
+                },
+                "member": { "join_timestamp": "2021-07-01T00:00:00Z", "flags": 1 },
+            }],
+        }));
+        match &event.channels[..] {
+            [Channel::GuildStage(stage), Channel::Unknown(forum), Channel::Unknown(future)] => {
+                assert_eq!((stage.id, stage.bitrate, stage.nsfw, stage.user_limit), (ChannelId::from(10), 64000, false, 0));
+                assert_eq!((forum.code, forum.name.as_deref()), (15, Some("help")));
+                assert_eq!((future.code, future.guild_id), (99, Some(1.into())));
+            },
+            _ => panic!("expected a stage channel followed by two unknown channels"),
+        }
+        match &event.threads[..] {
+            [Channel::Thread(thread)] => {
+                assert_eq!(thread.kind, ChannelType::GuildPublicThread);
+                assert_eq!(thread.parent_id, Some(ChannelId::from(30)));
+                assert_eq!(thread.message_count, Some(3));
+                assert_eq!(thread.thread_metadata.auto_archive_duration, 1440);
+                assert_eq!(thread.member.as_ref().map(|member| (member.user_id, member.flags)), Some((None, 1)));
+            },
+            _ => panic!("expected a single thread"),
+        }
+    }
+
+    #[test]
+    fn stages_and_scheduled_events() {
+        let event = guild_create(json!({
+            "stage_instances": [{
+                "id": "40", "guild_id": "1", "channel_id": "10", "topic": "Q&A", "privacy_level": 2,
+                "discoverable_disabled": false, "guild_scheduled_event_id": "50",
+            }],
+            "guild_scheduled_events": [
+                {
+                    "id": "50", "guild_id": "1", "channel_id": "10", "creator_id": "100", "name": "Q&A",
+                    "description": "Ask us anything", "scheduled_start_time": "2022-01-01T00:00:00Z", "scheduled_end_time": null,
+                    "privacy_level": 2, "status": 2, "entity_type": 1, "entity_id": "40", "entity_metadata": null,
+                    "user_count": 12, "image": null,
+                },
+                {
+                    "id": "51", "guild_id": "1", "channel_id": null, "creator_id": "100", "name": "Picnic",
+                    "scheduled_start_time": "2022-01-02T00:00:00Z", "scheduled_end_time": "2022-01-02T04:00:00Z",
+                    "privacy_level": 2, "status": 1, "entity_type": 3, "entity_id": null,
+                    "entity_metadata": { "location": "The park" },
+                },
+            ],
+        }));
+        let stage = &event.stage_instances[0];
+        assert_eq!((stage.channel_id, stage.privacy_level), (ChannelId::from(10), PrivacyLevel::GuildOnly));
+        assert_eq!(stage.guild_scheduled_event_id, Some(50.into()));
+        match &event.guild_scheduled_events[..] {
+            [live, external] => {
+                assert_eq!((live.status, live.entity_type), (ScheduledEventStatus::Active, ScheduledEventEntityType::StageInstance));
+                assert_eq!((live.entity_id, live.user_count), (Some(Snowflake::from(40)), Some(12)));
+                assert_eq!((external.status, external.entity_type), (ScheduledEventStatus::Scheduled, ScheduledEventEntityType::External));
+                assert_eq!(external.entity_metadata.as_ref().and_then(|metadata| metadata.location.as_deref()), Some("The park"));
+                assert!(external.scheduled_end_time.is_some());
+            },
+            _ => panic!("expected two scheduled events"),
+        }
+    }
+
+    #[test]
+    fn without_threads() {
+        let event = guild_create(json!({}));
+        assert!(event.threads.is_empty());
+        assert!(event.stage_instances.is_empty() && event.guild_scheduled_events.is_empty());
+        assert_eq!(event.member_count, 2);
+    }
+}
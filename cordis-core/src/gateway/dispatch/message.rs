@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use super::super::super::resources::{ChannelId, Emoji, GuildMember, GuildId, Message, MessageId, User, UserId};
+use super::super::super::resources::{ChannelId, GuildMember, GuildId, Message, MessageId, ReactionType, User, UserId};
 
 /// Sent when a message has been created.
 #[derive(Deserialize)]
@@ -60,7 +60,7 @@ pub struct MessageReactionAdd {
     /// The guild member that reacted.
     pub member: Option<GuildMember>,
     /// The emoji used in the reaction.
-    pub emoji: Emoji,
+    pub emoji: ReactionType,
 }
 
 /// Sent when a user removes a reaction to a message.
@@ -75,7 +75,7 @@ pub struct MessageReactionRemove {
     /// Id of the guild.
     pub guild_id: Option<GuildId>,
     /// The emoji used in the reaction.
-    pub emoji: Emoji,
+    pub emoji: ReactionType,
 }
 
 /// Sent when a user explicitly removes all reactions from a message.
@@ -87,4 +87,21 @@ pub struct MessageReactionRemoveAll {
     pub message_id: MessageId,
     /// Id of the guild.
     pub guild_id: Option<GuildId>,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+
+    #[test]
+    fn reaction_with_unicode_emoji() {
+        let event: MessageReactionAdd = from_value(json!({
+            "user_id": "1", "channel_id": "2", "message_id": "3", "guild_id": "4",
+            "emoji": { "id": null, "name": "👍" },
+        })).unwrap();
+        assert_eq!(event.emoji, ReactionType::Unicode("👍".to_string()));
+        assert!(event.member.is_none());
+    }
+}
@@ -3,7 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::Presence;
-use super::super::resources::{Channel, Emoji, Guild, GuildId, GuildMember, Role, RoleId, User, UserId};
+use super::super::resources::{Channel, Emoji, Guild, GuildId, GuildMember, GuildScheduledEvent, Role, RoleId, ScheduledEventId, StageInstance, Sticker, User, UserId};
 
 mod channel_pins_update;
 pub use channel_pins_update::ChannelPinsUpdate;
@@ -11,9 +11,17 @@ mod guild_create;
 pub use guild_create::{ClientStatus, GuildCreate, PartialPresenceUpdate, PartialVoiceState, PartialUser};
 mod message;
 pub use message::{MessageUpdate, MessageDelete, MessageDeleteBulk, MessageReactionAdd, MessageReactionRemove, MessageReactionRemoveAll, UserWithMember};
+mod ready;
+pub use ready::Ready;
+mod thread;
+pub use thread::{ThreadDelete, ThreadListSync, ThreadMembersUpdate};
 
 /// An event dispatched from the server.
 pub enum DispatchEvent {
+    /// Sent once the client has identified, contains the initial state of the session.
+    Ready(Ready),
+    /// Sent once the client has resumed and all missed events have been replayed.
+    Resumed,
     /// Sent when a new channel has been created, relative to the user.
     ChannelCreate(Channel),
     /// Sent when a channel is update.
@@ -26,6 +34,16 @@ pub enum DispatchEvent {
     ///
     /// Not sent when a pinned message is deleted.
     ChannelPinsUpdate(ChannelPinsUpdate),
+    /// Sent when a thread is created, or when the current user is added to a private thread.
+    ThreadCreate(Channel),
+    /// Sent when a thread is updated.
+    ThreadUpdate(Channel),
+    /// Sent when a thread is deleted.
+    ThreadDelete(ThreadDelete),
+    /// Sent when the current user gains access to a channel, with the active threads in it.
+    ThreadListSync(ThreadListSync),
+    /// Sent when users are added to or removed from a thread.
+    ThreadMembersUpdate(ThreadMembersUpdate),
     /// Lazy load for unavailable guild, guild became available, or user joined a new guild.
     GuildCreate(GuildCreate),
     /// Sent when a guild is updated.
@@ -38,6 +56,8 @@ pub enum DispatchEvent {
     GuildBanRemove(GuildBan),
     /// Sent when a guild's emojis have been updated.
     GuildEmojisUpdate(GuildEmojisUpdate),
+    /// Sent when a guild's stickers have been updated.
+    GuildStickersUpdate(GuildStickersUpdate),
     /// Sent when a guild integration is update.
     GuildIntegrationsUpdate(GuildIntegrationsUpdate),
     /// Sent when a user joins a guild.
@@ -54,6 +74,22 @@ pub enum DispatchEvent {
     GuildRoleUpdate(GuildRole),
     /// Sent when a guild role is deleted.
     GuildRoleDelete(GuildRoleDelete),
+    /// Sent when an event is scheduled in a guild.
+    GuildScheduledEventCreate(GuildScheduledEvent),
+    /// Sent when a scheduled event is updated, including when it starts or ends.
+    GuildScheduledEventUpdate(GuildScheduledEvent),
+    /// Sent when a scheduled event is deleted.
+    GuildScheduledEventDelete(GuildScheduledEvent),
+    /// Sent when a user subscribes to a scheduled event.
+    GuildScheduledEventUserAdd(GuildScheduledEventUser),
+    /// Sent when a user unsubscribes from a scheduled event.
+    GuildScheduledEventUserRemove(GuildScheduledEventUser),
+    /// Sent when a stage goes live.
+    StageInstanceCreate(StageInstance),
+    /// Sent when a stage is updated.
+    StageInstanceUpdate(StageInstance),
+    /// Sent when a stage ends.
+    StageInstanceDelete(StageInstance),
     /// Sent when a message has been created.
     MessageCreate(MessageUpdate),
     /// Sent when a message has been updated.
@@ -70,19 +106,78 @@ pub enum DispatchEvent {
     MessageReactionRemoveAll(MessageReactionRemoveAll),
 }
 
-#[derive(Deserialize, Serialize)]
+impl DispatchEvent {
+    /// The kind of this event.
+    pub fn kind(&self) -> EventKind {
+        match self {
+            DispatchEvent::Ready(_) => EventKind::Ready,
+            DispatchEvent::Resumed => EventKind::Resumed,
+            DispatchEvent::ChannelCreate(_) => EventKind::ChannelCreate,
+            DispatchEvent::ChannelUpdate(_) => EventKind::ChannelUpdate,
+            DispatchEvent::ChannelDelete(_) => EventKind::ChannelDelete,
+            DispatchEvent::ChannelPinsUpdate(_) => EventKind::ChannelPinsUpdate,
+            DispatchEvent::ThreadCreate(_) => EventKind::ThreadCreate,
+            DispatchEvent::ThreadUpdate(_) => EventKind::ThreadUpdate,
+            DispatchEvent::ThreadDelete(_) => EventKind::ThreadDelete,
+            DispatchEvent::ThreadListSync(_) => EventKind::ThreadListSync,
+            DispatchEvent::ThreadMembersUpdate(_) => EventKind::ThreadMembersUpdate,
+            DispatchEvent::GuildCreate(_) => EventKind::GuildCreate,
+            DispatchEvent::GuildUpdate(_) => EventKind::GuildUpdate,
+            DispatchEvent::GuildDelete(_) => EventKind::GuildDelete,
+            DispatchEvent::GuildBanAdd(_) => EventKind::GuildBanAdd,
+            DispatchEvent::GuildBanRemove(_) => EventKind::GuildBanRemove,
+            DispatchEvent::GuildEmojisUpdate(_) => EventKind::GuildEmojisUpdate,
+            DispatchEvent::GuildStickersUpdate(_) => EventKind::GuildStickersUpdate,
+            DispatchEvent::GuildIntegrationsUpdate(_) => EventKind::GuildIntegrationsUpdate,
+            DispatchEvent::GuildMemberAdd(_) => EventKind::GuildMemberAdd,
+            DispatchEvent::GuildMemberRemove(_) => EventKind::GuildMemberRemove,
+            DispatchEvent::GuildMemberUpdate(_) => EventKind::GuildMemberUpdate,
+            DispatchEvent::GuildMembersChunk(_) => EventKind::GuildMembersChunk,
+            DispatchEvent::GuildRoleAdd(_) => EventKind::GuildRoleAdd,
+            DispatchEvent::GuildRoleUpdate(_) => EventKind::GuildRoleUpdate,
+            DispatchEvent::GuildRoleDelete(_) => EventKind::GuildRoleDelete,
+            DispatchEvent::GuildScheduledEventCreate(_) => EventKind::GuildScheduledEventCreate,
+            DispatchEvent::GuildScheduledEventUpdate(_) => EventKind::GuildScheduledEventUpdate,
+            DispatchEvent::GuildScheduledEventDelete(_) => EventKind::GuildScheduledEventDelete,
+            DispatchEvent::GuildScheduledEventUserAdd(_) => EventKind::GuildScheduledEventUserAdd,
+            DispatchEvent::GuildScheduledEventUserRemove(_) => EventKind::GuildScheduledEventUserRemove,
+            DispatchEvent::StageInstanceCreate(_) => EventKind::StageInstanceCreate,
+            DispatchEvent::StageInstanceUpdate(_) => EventKind::StageInstanceUpdate,
+            DispatchEvent::StageInstanceDelete(_) => EventKind::StageInstanceDelete,
+            DispatchEvent::MessageCreate(_) => EventKind::MessageCreate,
+            DispatchEvent::MessageUpdate(_) => EventKind::MessageUpdate,
+            DispatchEvent::MessageDelete(_) => EventKind::MessageDelete,
+            DispatchEvent::MessageDeleteBulk(_) => EventKind::MessageDeleteBulk,
+            DispatchEvent::MessageReactionAdd(_) => EventKind::MessageReactionAdd,
+            DispatchEvent::MessageReactionRemove(_) => EventKind::MessageReactionRemove,
+            DispatchEvent::MessageReactionRemoveAll(_) => EventKind::MessageReactionRemoveAll,
+        }
+    }
+}
+
+/// The kind of an event dispatched from the server, without any of its data.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub(crate) enum DispatchEventCode {
+pub enum EventKind {
+    Ready,
+    Resumed,
     ChannelCreate,
     ChannelUpdate,
     ChannelDelete,
     ChannelPinsUpdate,
+    ThreadCreate,
+    ThreadUpdate,
+    ThreadDelete,
+    ThreadListSync,
+    ThreadMembersUpdate,
     GuildCreate,
     GuildUpdate,
     GuildDelete,
     GuildBanAdd,
     GuildBanRemove,
     GuildEmojisUpdate,
+    GuildStickersUpdate,
     GuildIntegrationsUpdate,
     GuildMemberAdd,
     GuildMemberRemove,
@@ -91,6 +186,14 @@ pub(crate) enum DispatchEventCode {
     GuildRoleAdd,
     GuildRoleUpdate,
     GuildRoleDelete,
+    GuildScheduledEventCreate,
+    GuildScheduledEventUpdate,
+    GuildScheduledEventDelete,
+    GuildScheduledEventUserAdd,
+    GuildScheduledEventUserRemove,
+    StageInstanceCreate,
+    StageInstanceUpdate,
+    StageInstanceDelete,
     MessageCreate,
     MessageUpdate,
     MessageDelete,
@@ -127,6 +230,15 @@ pub struct GuildEmojisUpdate {
     pub emojis: Vec<Emoji>,
 }
 
+/// Sent when a guild's stickers have been updated.
+#[derive(Deserialize)]
+pub struct GuildStickersUpdate {
+    /// The id of the guild.
+    pub guild_id: GuildId,
+    /// The list of stickers on the guild.
+    pub stickers: Vec<Sticker>,
+}
+
 /// Sent when a guild integration is updated.
 #[derive(Deserialize)]
 pub struct GuildIntegrationsUpdate {
@@ -195,4 +307,54 @@ pub struct GuildRoleDelete {
     pub guild_id: GuildId,
     /// Id of the role that was deleted.
     pub role_id: RoleId,
-}
\ No newline at end of file
+}
+
+/// Sent when a user subscribes to or unsubscribes from a scheduled event.
+#[derive(Deserialize)]
+pub struct GuildScheduledEventUser {
+    /// Id of the scheduled event.
+    pub guild_scheduled_event_id: ScheduledEventId,
+    /// Id of the user.
+    pub user_id: UserId,
+    /// Id of the guild.
+    pub guild_id: GuildId,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+    use super::super::ReceivedPayload;
+    use super::super::super::resources::{StickerFormatType, StickerType};
+
+    #[test]
+    fn guild_stickers_update() {
+        let payload = from_value(json!({
+            "op": 0, "s": 7, "t": "GUILD_STICKERS_UPDATE",
+            "d": {
+                "guild_id": "1",
+                "stickers": [
+                    {
+                        "id": "60", "name": "Wave", "description": null, "tags": "👋", "type": 2, "format_type": 1,
+                        "available": true, "guild_id": "1",
+                    },
+                    {
+                        "id": "61", "name": "Dance", "description": "", "tags": "💃", "type": 2, "format_type": 4,
+                        "available": false, "guild_id": "1",
+                    },
+                ],
+            },
+        })).unwrap();
+        let update = match payload {
+            ReceivedPayload::Dispatch { event: DispatchEvent::GuildStickersUpdate(update), seq: 7 } => update,
+            _ => panic!("expected GUILD_STICKERS_UPDATE"),
+        };
+        assert_eq!(update.guild_id, GuildId::from(1));
+        let stickers: Vec<_> = update.stickers.iter().map(|sticker| (sticker.kind, sticker.format_type, sticker.available)).collect();
+        assert_eq!(stickers, vec![
+            (StickerType::Guild, StickerFormatType::Png, Some(true)),
+            (StickerType::Guild, StickerFormatType::Gif, Some(false)),
+        ]);
+    }
+}
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use super::UnavailableGuild;
+use super::super::Shard;
+use super::super::super::resources::User;
+
+/// Sent once the client has identified, contains the initial state of the session.
+#[derive(Deserialize)]
+pub struct Ready {
+    /// The gateway protocol version.
+    pub v: u8,
+    /// The current user.
+    pub user: User,
+    /// The guilds the user is in, these are sent as `GuildCreate` events later.
+    pub guilds: Vec<UnavailableGuild>,
+    /// Used for resuming connections.
+    pub session_id: String,
+    /// The shard information associated with this session, if sent when identifying.
+    pub shard: Option<Shard>,
+    /// The gateway URL to use when resuming this session.
+    pub resume_gateway_url: Option<String>,
+}
@@ -0,0 +1,121 @@
+use serde::Deserialize;
+
+use super::super::super::resources::{
+    Channel, ChannelId, channel::{ChannelType, ThreadMember}, GuildId, UserId,
+};
+
+/// Sent when a thread is deleted.
+#[derive(Deserialize)]
+pub struct ThreadDelete {
+    /// The id of the thread.
+    pub id: ChannelId,
+    /// The id of the guild.
+    pub guild_id: GuildId,
+    /// The id of the channel the thread was in.
+    pub parent_id: Option<ChannelId>,
+    /// The type of thread.
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+}
+
+/// Sent when the current user gains access to a channel, with the active threads in it.
+#[derive(Deserialize)]
+pub struct ThreadListSync {
+    /// The id of the guild.
+    pub guild_id: GuildId,
+    /// The channels the threads are synced for, not sent when it is every channel of the guild.
+    #[serde(default)]
+    pub channel_ids: Vec<ChannelId>,
+    /// The active threads in the channels that the current user can access.
+    pub threads: Vec<Channel>,
+    /// The threads the current user has joined, as thread members.
+    pub members: Vec<ThreadMember>,
+}
+
+/// Sent when users are added to or removed from a thread.
+#[derive(Deserialize)]
+pub struct ThreadMembersUpdate {
+    /// The id of the thread.
+    pub id: ChannelId,
+    /// The id of the guild.
+    pub guild_id: GuildId,
+    /// An approximate count of the users in the thread, which stops counting at 50.
+    pub member_count: u32,
+    /// The users that were added to the thread.
+    #[serde(default)]
+    pub added_members: Vec<ThreadMember>,
+    /// The ids of the users that were removed from the thread.
+    #[serde(default)]
+    pub removed_member_ids: Vec<UserId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json, Value};
+
+    use super::*;
+    use super::super::DispatchEvent;
+    use super::super::super::ReceivedPayload;
+
+    fn dispatch(kind: &str, data: Value) -> DispatchEvent {
+        match from_value(json!({ "op": 0, "s": 42, "t": kind, "d": data })).unwrap() {
+            ReceivedPayload::Dispatch { event, seq: 42 } => event,
+            _ => panic!("expected a dispatch with the sequence number"),
+        }
+    }
+
+    #[test]
+    fn thread_list_sync() {
+        let event = dispatch("THREAD_LIST_SYNC", json!({
+            "guild_id": "1",
+            "channel_ids": ["30"],
+            "threads": [{
+                "id": "20", "type": 12, "guild_id": "1", "parent_id": "30", "owner_id": "100", "name": "Secret",
+                "thread_metadata": {
+                    "archived": false, "auto_archive_duration": 60, "archive_timestamp": "2021-07-01T00:00:00Z",
+                    "locked": false, "invitable": false,
+                },
+            }],
+            "members": [{ "id": "20", "user_id": "100", "join_timestamp": "2021-07-01T00:00:00Z", "flags": 0 }],
+        }));
+        let sync = match event {
+            DispatchEvent::ThreadListSync(sync) => sync,
+            _ => panic!("expected THREAD_LIST_SYNC"),
+        };
+        assert_eq!(sync.channel_ids, vec![ChannelId::from(30)]);
+        match &sync.threads[..] {
+            [Channel::Thread(thread)] => {
+                assert_eq!(thread.kind, ChannelType::GuildPrivateThread);
+                assert_eq!(thread.thread_metadata.invitable, Some(false));
+                assert!(thread.member.is_none());
+            },
+            _ => panic!("expected a single thread"),
+        }
+        assert_eq!(sync.members[0].id, Some(ChannelId::from(20)));
+        assert_eq!(sync.members[0].user_id, Some(UserId::from(100)));
+    }
+
+    #[test]
+    fn thread_list_sync_of_every_channel() {
+        let sync: ThreadListSync = from_value(json!({ "guild_id": "1", "threads": [], "members": [] })).unwrap();
+        assert!(sync.channel_ids.is_empty());
+    }
+
+    #[test]
+    fn thread_members_update() {
+        let event = dispatch("THREAD_MEMBERS_UPDATE", json!({
+            "id": "20", "guild_id": "1", "member_count": 50,
+            "added_members": [{ "id": "20", "user_id": "101", "join_timestamp": "2021-07-01T00:00:00Z", "flags": 1 }],
+            "removed_member_ids": ["102", "103"],
+        }));
+        let update = match event {
+            DispatchEvent::ThreadMembersUpdate(update) => update,
+            _ => panic!("expected THREAD_MEMBERS_UPDATE"),
+        };
+        assert_eq!((update.id, update.member_count), (ChannelId::from(20), 50));
+        assert_eq!(update.added_members[0].user_id, Some(UserId::from(101)));
+        assert_eq!(update.removed_member_ids, vec![UserId::from(102), UserId::from(103)]);
+        let update: ThreadMembersUpdate = from_value(json!({ "id": "20", "guild_id": "1", "member_count": 1 })).unwrap();
+        assert!(update.added_members.is_empty() && update.removed_member_ids.is_empty());
+    }
+}
@@ -1,6 +1,6 @@
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use super::StatusUpdate;
+use super::{StatusUpdate, ValidationError};
 
 /// Information used for the client handshake.
 #[derive(Serialize)]
@@ -42,6 +42,75 @@ impl Default for Identity {
     }
 }
 
+impl Identity {
+    /// Starts building an `Identity` that is validated when it is built.
+    pub fn builder(token: impl Into<String>) -> IdentityBuilder {
+        IdentityBuilder(Identity {
+            token: token.into(),
+            ..Identity::default()
+        })
+    }
+
+    /// Checks that Discord will accept this handshake.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.token.is_empty() {
+            return Err(ValidationError::EmptyToken);
+        }
+        if !(50..=250).contains(&self.large_threshold) {
+            return Err(ValidationError::LargeThreshold(self.large_threshold));
+        }
+        self.shard.validate()?;
+        self.presence.validate()
+    }
+}
+
+/// Builds an [`Identity`](struct.Identity.html).
+pub struct IdentityBuilder(Identity);
+
+impl IdentityBuilder {
+    /// Sets the connection properties.
+    pub fn properties(mut self, properties: ConnectionProperties) -> Self {
+        self.0.properties = properties;
+        self
+    }
+
+    /// Sets whether this connection supports compression of packets.
+    pub fn compress(mut self, compress: bool) -> Self {
+        self.0.compress = compress;
+        self
+    }
+
+    /// Sets the member count (between 50 and 250) at which offline members stop being sent.
+    pub fn large_threshold(mut self, large_threshold: u16) -> Self {
+        self.0.large_threshold = large_threshold;
+        self
+    }
+
+    /// Sets the shard this connection is for.
+    pub fn shard(mut self, shard_id: u16, num_shards: u16) -> Self {
+        self.0.shard = Shard { shard_id, num_shards };
+        self
+    }
+
+    /// Sets the initial presence information.
+    pub fn presence(mut self, presence: StatusUpdate) -> Self {
+        self.0.presence = presence;
+        self
+    }
+
+    /// Sets whether presence and typing events are dispatched.
+    pub fn guild_subscription(mut self, guild_subscription: bool) -> Self {
+        self.0.guild_subscription = guild_subscription;
+        self
+    }
+
+    /// Validates and returns the handshake.
+    pub fn build(self) -> Result<Identity, ValidationError> {
+        self.0.validate()?;
+        Ok(self.0)
+    }
+}
+
 /// The properties of the connection the server, given when identifying.
 ///
 /// See [the official Discord documentation for more information.](https://discordapp.com/developers/docs/topics/gateway#identify-identify-connection-properties)
@@ -70,12 +139,23 @@ impl Default for ConnectionProperties {
 /// Used for guild sharding.
 ///
 /// [See Official Discord documentation for more information.](https://discordapp.com/developers/docs/topics/gateway#sharding)
+#[derive(Clone, Copy)]
 pub struct Shard {
     /// The id of the current shard.
     pub shard_id: u16,
     /// The number of shards.
     pub num_shards: u16,
 }
+impl Shard {
+    /// Checks that the shard id is less than the number of shards.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.shard_id < self.num_shards {
+            Ok(())
+        } else {
+            Err(ValidationError::ShardOutOfRange { shard_id: self.shard_id, num_shards: self.num_shards })
+        }
+    }
+}
 impl Default for Shard {
     fn default() -> Self {
         Self {
@@ -94,4 +174,50 @@ impl Serialize for Shard {
         seq.serialize_element(&self.num_shards)?;
         seq.end()
     }
-}
\ No newline at end of file
+}
+impl<'de> Deserialize<'de> for Shard {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        let (shard_id, num_shards) = <(u16, u16)>::deserialize(deserializer)?;
+        Ok(Self { shard_id, num_shards })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::Activity;
+
+    #[test]
+    fn token() {
+        assert!(Identity::builder("token").build().is_ok());
+        assert_eq!(Identity::builder("").build().err(), Some(ValidationError::EmptyToken));
+    }
+
+    #[test]
+    fn large_threshold() {
+        assert!(Identity::builder("token").large_threshold(50).build().is_ok());
+        assert!(Identity::builder("token").large_threshold(250).build().is_ok());
+        assert_eq!(Identity::builder("token").large_threshold(49).build().err(), Some(ValidationError::LargeThreshold(49)));
+        assert_eq!(Identity::builder("token").large_threshold(251).build().err(), Some(ValidationError::LargeThreshold(251)));
+    }
+
+    #[test]
+    fn shard() {
+        assert!(Identity::builder("token").shard(1, 2).build().is_ok());
+        assert_eq!(
+            Identity::builder("token").shard(2, 2).build().err(),
+            Some(ValidationError::ShardOutOfRange { shard_id: 2, num_shards: 2 }),
+        );
+    }
+
+    #[test]
+    fn presence() {
+        let streaming = StatusUpdate { activities: vec![Activity::streaming("Cordis", "ftp://twitch.tv/cordis")], ..StatusUpdate::default() };
+        assert_eq!(
+            Identity::builder("token").presence(streaming).build().err(),
+            Some(ValidationError::StreamingUrl(Some("ftp://twitch.tv/cordis".to_string()))),
+        );
+    }
+}
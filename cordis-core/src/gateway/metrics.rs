@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use super::{ReceivedPayload, SendablePayload};
+use super::dispatch::EventKind;
+
+/// A snapshot of how healthy a shard's connection is.
+#[derive(Clone, Default)]
+pub struct ShardStats {
+    /// The time between the last heartbeat and its ACK.
+    pub last_latency: Option<Duration>,
+    /// The average time between a heartbeat and its ACK.
+    pub average_latency: Option<Duration>,
+    /// The number of heartbeats that were not ACKed before the next heartbeat was sent or the shard reconnected.
+    pub missed_acks: u32,
+    /// The number of events received of each kind.
+    pub events: HashMap<EventKind, u64>,
+    /// The number of bytes received from the gateway.
+    pub bytes_received: u64,
+    /// The number of bytes sent to the gateway.
+    pub bytes_sent: u64,
+    /// The number of times the shard has reconnected.
+    pub reconnects: u32,
+}
+
+/// Records the traffic of a shard so that [`ShardStats`](struct.ShardStats.html) can be read from it.
+///
+/// The times are passed in rather than read from the clock so that they can be taken
+/// when the payload actually went over the wire.
+#[derive(Default)]
+pub struct ShardMetrics {
+    stats: ShardStats,
+    heartbeat_sent: Option<Instant>,
+    acked: u32,
+    total_latency: Duration,
+}
+
+impl ShardMetrics {
+    /// Creates metrics for a shard that hasn't connected yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a payload of `bytes` length being sent at the given time.
+    pub fn sent(&mut self, payload: &SendablePayload, bytes: usize, at: Instant) {
+        self.stats.bytes_sent += bytes as u64;
+        if let SendablePayload::Heartbeat(_) = payload {
+            if self.heartbeat_sent.replace(at).is_some() {
+                self.stats.missed_acks += 1;
+            }
+        }
+    }
+
+    /// Records a payload of `bytes` length being received at the given time.
+    pub fn received(&mut self, payload: &ReceivedPayload, bytes: usize, at: Instant) {
+        self.stats.bytes_received += bytes as u64;
+        match payload {
+            ReceivedPayload::Dispatch { event, .. } => *self.stats.events.entry(event.kind()).or_insert(0) += 1,
+            ReceivedPayload::HeartbeatACK => if let Some(sent) = self.heartbeat_sent.take() {
+                let latency = at.saturating_duration_since(sent);
+                self.acked += 1;
+                self.total_latency += latency;
+                self.stats.last_latency = Some(latency);
+                self.stats.average_latency = Some(self.total_latency / self.acked);
+            },
+            _ => {},
+        }
+    }
+
+    /// Records the shard reconnecting to the gateway.
+    pub fn reconnected(&mut self) {
+        self.stats.reconnects += 1;
+        if self.heartbeat_sent.take().is_some() {
+            self.stats.missed_acks += 1;
+        }
+    }
+
+    /// The stats recorded so far.
+    pub fn snapshot(&self) -> ShardStats {
+        self.stats.clone()
+    }
+
+    /// Starts recording from zero again, e.g. at the start of each reporting interval.
+    ///
+    /// A heartbeat that is still waiting for its ACK is kept so its latency is still recorded.
+    pub fn reset(&mut self) {
+        self.stats = ShardStats::default();
+        self.acked = 0;
+        self.total_latency = Duration::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::from_str;
+
+    use super::*;
+
+    fn dispatch(kind: &str, data: &str) -> ReceivedPayload {
+        from_str(&format!(r#"{{"op":0,"s":1,"t":"{}","d":{}}}"#, kind, data)).unwrap()
+    }
+
+    fn heartbeat(metrics: &mut ShardMetrics, start: Instant, sent: u64, acked: u64) {
+        metrics.sent(&SendablePayload::Heartbeat(None), 10, start + Duration::from_millis(sent));
+        metrics.received(&ReceivedPayload::HeartbeatACK, 5, start + Duration::from_millis(acked));
+    }
+
+    #[test]
+    fn counts_events_and_bytes() {
+        let mut metrics = ShardMetrics::new();
+        let now = Instant::now();
+        metrics.received(&dispatch("GUILD_INTEGRATIONS_UPDATE", r#"{"guild_id":"1"}"#), 100, now);
+        metrics.received(&dispatch("GUILD_INTEGRATIONS_UPDATE", r#"{"guild_id":"1"}"#), 100, now);
+        metrics.received(&dispatch("MESSAGE_DELETE", r#"{"id":"2","channel_id":"3"}"#), 50, now);
+        let stats = metrics.snapshot();
+        assert_eq!(stats.events.get(&EventKind::GuildIntegrationsUpdate), Some(&2));
+        assert_eq!(stats.events.get(&EventKind::MessageDelete), Some(&1));
+        assert_eq!(stats.events.get(&EventKind::Ready), None);
+        assert_eq!(stats.bytes_received, 250);
+    }
+
+    #[test]
+    fn averages_latency() {
+        let mut metrics = ShardMetrics::new();
+        let start = Instant::now();
+        heartbeat(&mut metrics, start, 0, 100);
+        heartbeat(&mut metrics, start, 1000, 1300);
+        let stats = metrics.snapshot();
+        assert_eq!(stats.last_latency, Some(Duration::from_millis(300)));
+        assert_eq!(stats.average_latency, Some(Duration::from_millis(200)));
+        assert_eq!((stats.bytes_sent, stats.bytes_received), (20, 10));
+    }
+
+    #[test]
+    fn missed_acks() {
+        let mut metrics = ShardMetrics::new();
+        let start = Instant::now();
+        metrics.sent(&SendablePayload::Heartbeat(None), 10, start);
+        metrics.sent(&SendablePayload::Heartbeat(None), 10, start + Duration::from_millis(1000));
+        metrics.reconnected();
+        metrics.received(&ReceivedPayload::HeartbeatACK, 5, start + Duration::from_millis(2000));
+        let stats = metrics.snapshot();
+        assert_eq!((stats.missed_acks, stats.reconnects), (2, 1));
+        assert_eq!(stats.last_latency, None);
+    }
+
+    #[test]
+    fn snapshot_and_reset() {
+        let mut metrics = ShardMetrics::new();
+        let start = Instant::now();
+        heartbeat(&mut metrics, start, 0, 400);
+        let snapshot = metrics.snapshot();
+        metrics.sent(&SendablePayload::Heartbeat(None), 10, start + Duration::from_millis(1000));
+        metrics.reset();
+        assert_eq!(snapshot.average_latency, Some(Duration::from_millis(400)));
+        assert_eq!(metrics.snapshot().bytes_sent, 0);
+        assert_eq!(metrics.snapshot().average_latency, None);
+
+        metrics.received(&ReceivedPayload::HeartbeatACK, 5, start + Duration::from_millis(1100));
+        assert_eq!(metrics.snapshot().average_latency, Some(Duration::from_millis(100)));
+    }
+}
@@ -2,6 +2,10 @@
 
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
+mod activity;
+pub use activity::{Activity, ActivityAssets, ActivityFlags, ActivityParty, ActivitySecrets, ActivityTimestamps, ActivityType};
+mod metrics;
+pub use metrics::{ShardMetrics, ShardStats};
 mod payload;
 pub use payload::{ReceivedPayload, SendablePayload};
 pub mod dispatch;
@@ -10,15 +14,19 @@ pub use hello::Hello;
 mod presence;
 pub use presence::Presence;
 mod identity;
-pub use identity::{ConnectionProperties, Identity};
+pub use identity::{ConnectionProperties, Identity, IdentityBuilder, Shard};
 mod status_update;
-pub use status_update::{Activity, ActivityType, Status, StatusUpdate};
+pub use status_update::{Status, StatusUpdate, StatusUpdateBuilder};
 mod request_guild_members;
-pub use request_guild_members::RequestGuildMembers;
+pub use request_guild_members::{RequestGuildMembers, RequestGuildMembersBuilder};
 mod resume;
-pub use resume::Resume;
+pub use resume::{Resume, ResumeBuilder};
+mod session;
+pub use session::{FileSessionStore, Sequence, Session, SessionState, SessionStore};
+mod validation;
+pub use validation::ValidationError;
 mod voice_state_update;
-pub use voice_state_update::VoiceStateUpdate;
+pub use voice_state_update::{VoiceStateUpdate, VoiceStateUpdateBuilder};
 
 /// The API version of the gateway this crate will support.
 pub const VERSION: u8 = 6;
@@ -27,12 +35,13 @@ pub const VERSION: u8 = 6;
 ///
 /// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/topics/opcodes-and-status-codes#gateway-opcodes)
 #[allow(missing_docs)]
-#[derive(Deserialize_repr)]
+#[derive(Default, Deserialize_repr)]
 #[repr(u8)]
 enum RecvOpCode {
     /// Dispatches an event.
     Dispatch = 0,
     /// Used for ping checking.
+    #[default]
     Heartbeat = 1,
     /// Used to tell clients to reconnect to the gateway.
     Reconnect = 7,
@@ -50,10 +59,11 @@ enum RecvOpCode {
 /// All the different operations that can be *sent* by the client to the server.
 ///
 /// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/topics/opcodes-and-status-codes#gateway-opcodes)
-#[derive(Serialize_repr)]
+#[derive(Default, Serialize_repr)]
 #[repr(u8)]
 enum SendOpCode {
     /// Used for ping checking.
+    #[default]
     Heartbeat = 1,
     /// Used for client handshake.
     Identity = 2,
@@ -66,15 +76,3 @@ enum SendOpCode {
     /// Used to request guild members.
     RequestGuildMembers = 8,
 }
-
-impl Default for RecvOpCode {
-    fn default() -> Self {
-        Self::Heartbeat
-    }
-}
-
-impl Default for SendOpCode {
-    fn default() -> Self {
-        Self::Heartbeat
-    }
-}
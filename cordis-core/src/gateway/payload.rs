@@ -2,19 +2,20 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{from_value, to_value, Value as JsonValue};
 
 use super::{RecvOpCode, SendOpCode};
-use super::{dispatch::{DispatchEvent, DispatchEventCode}, Hello, Identity, RequestGuildMembers, Resume, StatusUpdate, VoiceStateUpdate};
+use super::{dispatch::{DispatchEvent, EventKind}, Hello, Identity, RequestGuildMembers, Resume, StatusUpdate, VoiceStateUpdate};
 
 #[derive(Default, Deserialize, Serialize)]
 struct InitialPayload<O> {
     op: O,
     d: Option<JsonValue>,
     s: Option<u32>,
-    t: Option<DispatchEventCode>,
+    t: Option<EventKind>,
 }
 
 /// All the different payloads that can be *received* by the client from the server.
 ///
 /// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/topics/opcodes-and-status-codes#gateway-opcodes)
+#[allow(clippy::large_enum_variant)]
 pub enum ReceivedPayload {
     /// Dispatches an event.
     Dispatch {
@@ -58,103 +59,167 @@ impl<'de> Deserialize<'de> for ReceivedPayload {
             RecvOpCode::Hello => Ok(ReceivedPayload::Hello(from_value(initial_payload.d.expect("Expected data in Hello event.")).expect("Could not parse `Hello` Payload data"))),
             RecvOpCode::HeartbeatACK => Ok(ReceivedPayload::HeartbeatACK),
             RecvOpCode::Dispatch => match initial_payload.t.expect("Could not find Dispatch Event Code")  {
-                DispatchEventCode::ChannelCreate => Ok(ReceivedPayload::Dispatch{
+                EventKind::Ready => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::Ready(from_value(initial_payload.d.expect("Expected data in `Ready` event.")).expect("Could not parse `Ready` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `Ready` event"),
+                }),
+                EventKind::Resumed => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::Resumed,
+                    seq: initial_payload.s.expect("Expected sequence number in `Resumed` event"),
+                }),
+                EventKind::ChannelCreate => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::ChannelCreate(from_value(initial_payload.d.expect("Expected data in `ChannelCreate` event.")).expect("Could not parse `ChannelCreate` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `ChannelCreate` event"),
                 }),
-                DispatchEventCode::ChannelUpdate => Ok(ReceivedPayload::Dispatch{
+                EventKind::ChannelUpdate => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::ChannelUpdate(from_value(initial_payload.d.expect("Expected data in `ChannelUpdate` event.")).expect("Could not parse `ChannelUpdate` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `ChannelUpdate` event"),
                 }),
-                DispatchEventCode::ChannelDelete => Ok(ReceivedPayload::Dispatch{
+                EventKind::ChannelDelete => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::ChannelDelete(from_value(initial_payload.d.expect("Expected data in `ChannelDelete` event.")).expect("Could not parse `ChannelDelete` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `ChannelDelete` event"),
                 }),
-                DispatchEventCode::ChannelPinsUpdate => Ok(ReceivedPayload::Dispatch{
+                EventKind::ChannelPinsUpdate => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::ChannelPinsUpdate(from_value(initial_payload.d.expect("Expected data in `ChannelPinsUpdate` event.")).expect("Could not parse `ChannelPinsUpdate` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `ChanneldPinsUpdate` event"),
                 }),
-                DispatchEventCode::GuildCreate => Ok(ReceivedPayload::Dispatch{
+                EventKind::ThreadCreate => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::ThreadCreate(from_value(initial_payload.d.expect("Expected data in `ThreadCreate` event.")).expect("Could not parse `ThreadCreate` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `ThreadCreate` event"),
+                }),
+                EventKind::ThreadUpdate => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::ThreadUpdate(from_value(initial_payload.d.expect("Expected data in `ThreadUpdate` event.")).expect("Could not parse `ThreadUpdate` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `ThreadUpdate` event"),
+                }),
+                EventKind::ThreadDelete => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::ThreadDelete(from_value(initial_payload.d.expect("Expected data in `ThreadDelete` event.")).expect("Could not parse `ThreadDelete` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `ThreadDelete` event"),
+                }),
+                EventKind::ThreadListSync => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::ThreadListSync(from_value(initial_payload.d.expect("Expected data in `ThreadListSync` event.")).expect("Could not parse `ThreadListSync` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `ThreadListSync` event"),
+                }),
+                EventKind::ThreadMembersUpdate => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::ThreadMembersUpdate(from_value(initial_payload.d.expect("Expected data in `ThreadMembersUpdate` event.")).expect("Could not parse `ThreadMembersUpdate` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `ThreadMembersUpdate` event"),
+                }),
+                EventKind::GuildCreate => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildCreate(from_value(initial_payload.d.expect("Expected data in `GuildCreate` event.")).expect("Could not parse `GuildCreate` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildCreate` event"),
                 }),
-                DispatchEventCode::GuildUpdate => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildUpdate => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildUpdate(from_value(initial_payload.d.expect("Expected data in `GuildUpdate` event.")).expect("Could not parse `GuildUpdate` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildUpdate` event"),
                 }),
-                DispatchEventCode::GuildDelete => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildDelete => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildDelete(from_value(initial_payload.d.expect("Expected data in `GuildDelete` event.")).expect("Could not parse `GuildDelete` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildDelete` event"),
                 }),
-                DispatchEventCode::GuildBanAdd => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildBanAdd => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildBanAdd(from_value(initial_payload.d.expect("Expected data in `GuildBanAdd` event.")).expect("Could not parse `GuildBanAdd` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildBanAdd` event"),
                 }),
-                DispatchEventCode::GuildBanRemove => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildBanRemove => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildBanRemove(from_value(initial_payload.d.expect("Expected data in `GuildBanRemove` event.")).expect("Could not parse `GuildBanRemove` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildBanRemove` event"),
                 }),
-                DispatchEventCode::GuildEmojisUpdate => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildEmojisUpdate => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildEmojisUpdate(from_value(initial_payload.d.expect("Expected data in `GuildEmojisUpdate` event.")).expect("Could not parse `GuildEmojisUpdate` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildEmojisUpdate` event"),
                 }),
-                DispatchEventCode::GuildIntegrationsUpdate => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildStickersUpdate => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::GuildStickersUpdate(from_value(initial_payload.d.expect("Expected data in `GuildStickersUpdate` event.")).expect("Could not parse `GuildStickersUpdate` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `GuildStickersUpdate` event"),
+                }),
+                EventKind::GuildIntegrationsUpdate => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildIntegrationsUpdate(from_value(initial_payload.d.expect("Expected data in `GuildIntegrationsUpdate` event.")).expect("Could not parse `GuildIntegrationsUpdate` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildIntegrationsUpdate` event"),
                 }),
-                DispatchEventCode::GuildMemberAdd => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildMemberAdd => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildMemberAdd(from_value(initial_payload.d.expect("Expected data in `GuildMemberAdd` event.")).expect("Could not parse `GuildMemberAdd` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildMemberAdd` event"),
                 }),
-                DispatchEventCode::GuildMemberRemove => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildMemberRemove => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildMemberRemove(from_value(initial_payload.d.expect("Expected data in `GuildMemberRemove` event.")).expect("Could not parse `GuildMemberRemove` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildMemberRemove` event"),
                 }),
-                DispatchEventCode::GuildMemberUpdate => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildMemberUpdate => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildMemberUpdate(from_value(initial_payload.d.expect("Expected data in `GuildMemberUpdate` event.")).expect("Could not parse `GuildMemberUpdate` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildMemberUpdate` event"),
                 }),
-                DispatchEventCode::GuildMembersChunk => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildMembersChunk => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildMembersChunk(from_value(initial_payload.d.expect("Expected data in `GuildMembersChunk` event.")).expect("Could not parse `GuildMembersChunk` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildMembersChunk` event"),
                 }),
-                DispatchEventCode::GuildRoleAdd => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildRoleAdd => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildRoleAdd(from_value(initial_payload.d.expect("Expected data in `GuildRoleAdd` event.")).expect("Could not parse `GuildRoleAdd` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildRoleAdd` event"),
                 }),
-                DispatchEventCode::GuildRoleUpdate => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildRoleUpdate => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildRoleUpdate(from_value(initial_payload.d.expect("Expected data in `GuildRoleUpdate` event.")).expect("Could not parse `GuildRoleUpdate` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildRoleUpdate` event"),
                 }),
-                DispatchEventCode::GuildRoleDelete => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildRoleDelete => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::GuildRoleDelete(from_value(initial_payload.d.expect("Expected data in `GuildRoleDelete` event.")).expect("Could not parse `GuildRoleDelete` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `GuildRoleDelete` event"),
                 }),
-                DispatchEventCode::MessageCreate => Ok(ReceivedPayload::Dispatch{
+                EventKind::GuildScheduledEventCreate => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::GuildScheduledEventCreate(from_value(initial_payload.d.expect("Expected data in `GuildScheduledEventCreate` event.")).expect("Could not parse `GuildScheduledEventCreate` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `GuildScheduledEventCreate` event"),
+                }),
+                EventKind::GuildScheduledEventUpdate => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::GuildScheduledEventUpdate(from_value(initial_payload.d.expect("Expected data in `GuildScheduledEventUpdate` event.")).expect("Could not parse `GuildScheduledEventUpdate` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `GuildScheduledEventUpdate` event"),
+                }),
+                EventKind::GuildScheduledEventDelete => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::GuildScheduledEventDelete(from_value(initial_payload.d.expect("Expected data in `GuildScheduledEventDelete` event.")).expect("Could not parse `GuildScheduledEventDelete` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `GuildScheduledEventDelete` event"),
+                }),
+                EventKind::GuildScheduledEventUserAdd => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::GuildScheduledEventUserAdd(from_value(initial_payload.d.expect("Expected data in `GuildScheduledEventUserAdd` event.")).expect("Could not parse `GuildScheduledEventUserAdd` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `GuildScheduledEventUserAdd` event"),
+                }),
+                EventKind::GuildScheduledEventUserRemove => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::GuildScheduledEventUserRemove(from_value(initial_payload.d.expect("Expected data in `GuildScheduledEventUserRemove` event.")).expect("Could not parse `GuildScheduledEventUserRemove` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `GuildScheduledEventUserRemove` event"),
+                }),
+                EventKind::StageInstanceCreate => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::StageInstanceCreate(from_value(initial_payload.d.expect("Expected data in `StageInstanceCreate` event.")).expect("Could not parse `StageInstanceCreate` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `StageInstanceCreate` event"),
+                }),
+                EventKind::StageInstanceUpdate => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::StageInstanceUpdate(from_value(initial_payload.d.expect("Expected data in `StageInstanceUpdate` event.")).expect("Could not parse `StageInstanceUpdate` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `StageInstanceUpdate` event"),
+                }),
+                EventKind::StageInstanceDelete => Ok(ReceivedPayload::Dispatch{
+                    event: DispatchEvent::StageInstanceDelete(from_value(initial_payload.d.expect("Expected data in `StageInstanceDelete` event.")).expect("Could not parse `StageInstanceDelete` Payload data.")),
+                    seq: initial_payload.s.expect("Expected sequence number in `StageInstanceDelete` event"),
+                }),
+                EventKind::MessageCreate => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::MessageCreate(from_value(initial_payload.d.expect("Expected data in `MessageCreate` event.")).expect("Could not parse `MessageCreate` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `MessageCreate` event"),
                 }),
-                DispatchEventCode::MessageUpdate => Ok(ReceivedPayload::Dispatch{
+                EventKind::MessageUpdate => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::MessageUpdate(from_value(initial_payload.d.expect("Expected data in `MessageUpdate` event.")).expect("Could not parse `MessageUpdate` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `MessageUpdate` event"),
                 }),
-                DispatchEventCode::MessageDelete => Ok(ReceivedPayload::Dispatch{
+                EventKind::MessageDelete => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::MessageDelete(from_value(initial_payload.d.expect("Expected data in `MessageDelete` event.")).expect("Could not parse `MessageDelete` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `MessageDelete` event"),
                 }),
-                DispatchEventCode::MessageDeleteBulk => Ok(ReceivedPayload::Dispatch{
+                EventKind::MessageDeleteBulk => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::MessageDeleteBulk(from_value(initial_payload.d.expect("Expected data in `MessageDeleteBulk` event.")).expect("Could not parse `MessageDeleteBulk` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `MessageDeleteBulk` event"),
                 }),
-                DispatchEventCode::MessageReactionAdd => Ok(ReceivedPayload::Dispatch{
+                EventKind::MessageReactionAdd => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::MessageReactionAdd(from_value(initial_payload.d.expect("Expected data in `MessageReactionAdd` event.")).expect("Could not parse `MessageReactionAdd` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `MessageReactionAdd` event"),
                 }),
-                DispatchEventCode::MessageReactionRemove => Ok(ReceivedPayload::Dispatch{
+                EventKind::MessageReactionRemove => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::MessageReactionRemove(from_value(initial_payload.d.expect("Expected data in `MessageReactionRemove` event.")).expect("Could not parse `MessageReactionRemove` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `MessageReactionRemove` event"),
                 }),
-                DispatchEventCode::MessageReactionRemoveAll => Ok(ReceivedPayload::Dispatch{
+                EventKind::MessageReactionRemoveAll => Ok(ReceivedPayload::Dispatch{
                     event: DispatchEvent::MessageReactionRemoveAll(from_value(initial_payload.d.expect("Expected data in `MessageReactionRemoveAll` event.")).expect("Could not parse `MessageReactionRemoveAll` Payload data.")),
                     seq: initial_payload.s.expect("Expected sequence number in `MessageReactionRemoveAll` event"),
                 }),
@@ -199,7 +264,7 @@ impl Serialize for SendablePayload {
             SendablePayload::Identity(identity) => {
                 InitialPayload {
                     op: SendOpCode::Identity,
-                    d: Some(to_value(&identity).expect("Error serialising `Identity` for Identity")),
+                    d: Some(to_value(identity).expect("Error serialising `Identity` for Identity")),
                     ..InitialPayload::default()
                 }
             },
@@ -13,11 +13,12 @@ pub struct Presence {
     pub user: PartialUser,
     /// Roles the user is in.
     pub roles: Vec<RoleId>,
-    /// The user's current activity.
+    /// The user's current activity, which is the first of `activities`.
     pub game: Option<Activity>,
     /// The status of the user.
     pub status: Status,
     /// User's current activities.
+    #[serde(default)]
     pub activities: Vec<Activity>,
     /// User's platform-dependant status.
     pub client_status: ClientStatus,
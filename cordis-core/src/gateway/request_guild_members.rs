@@ -1,5 +1,8 @@
 use serde::Serialize;
 
+use super::ValidationError;
+use super::super::resources::{GuildId, UserId};
+
 /// Used to request all members for a guild or list of guilds.
 ///
 /// When initially connecting, the gateway will only send offline members if a guild has less than the
@@ -12,8 +15,9 @@ use serde::Serialize;
 #[derive(Serialize)]
 pub struct RequestGuildMembers {
     /// Id of the guild(s) to get members for.
-    pub guild_id: Vec<String>,
+    pub guild_id: Vec<GuildId>,
     /// String that the username starts with, or an empty string to return all members.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub query: Option<String>, 
     /// maximum number of members to send matching the `query`; a limit of `0` can be used with an empty string
     /// to return all members.
@@ -21,7 +25,8 @@ pub struct RequestGuildMembers {
     /// Used to specify if we want the presences of the matched members
     pub presences: bool,
     /// Used to specify which users you wish to fetch.
-    pub user_ids : Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_ids : Option<Vec<UserId>>,
 }
 impl Default for RequestGuildMembers {
     fn default() -> Self {
@@ -33,4 +38,125 @@ impl Default for RequestGuildMembers {
             user_ids: None,
         }
     }
-}
\ No newline at end of file
+}
+
+impl RequestGuildMembers {
+    /// Starts building a `RequestGuildMembers` that is validated when it is built.
+    ///
+    /// All members are requested unless a `query` or `user_ids` are given.
+    pub fn builder(guild_id: GuildId) -> RequestGuildMembersBuilder {
+        RequestGuildMembersBuilder {
+            guild_id: vec![guild_id],
+            query: None,
+            limit: 0,
+            presences: false,
+            user_ids: None,
+        }
+    }
+
+    /// Checks that Discord will accept this request.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.guild_id.is_empty() {
+            return Err(ValidationError::NoGuildIds);
+        }
+        match (&self.query, &self.user_ids) {
+            (Some(_), Some(_)) => Err(ValidationError::QueryAndUserIds),
+            (_, Some(user_ids)) if user_ids.len() > 100 => Err(ValidationError::TooManyUserIds(user_ids.len())),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Builds a [`RequestGuildMembers`](struct.RequestGuildMembers.html).
+pub struct RequestGuildMembersBuilder {
+    guild_id: Vec<GuildId>,
+    query: Option<String>,
+    limit: u32,
+    presences: bool,
+    user_ids: Option<Vec<UserId>>,
+}
+
+impl RequestGuildMembersBuilder {
+    /// Adds another guild to get members for.
+    pub fn guild(mut self, guild_id: GuildId) -> Self {
+        self.guild_id.push(guild_id);
+        self
+    }
+
+    /// Only requests members whose username starts with the query.
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Sets the maximum number of members to send.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Sets whether the presences of the members are sent as well.
+    pub fn presences(mut self, presences: bool) -> Self {
+        self.presences = presences;
+        self
+    }
+
+    /// Only requests the given users (up to 100).
+    pub fn user_ids(mut self, user_ids: Vec<UserId>) -> Self {
+        self.user_ids = Some(user_ids);
+        self
+    }
+
+    /// Validates and returns the request.
+    pub fn build(self) -> Result<RequestGuildMembers, ValidationError> {
+        let RequestGuildMembersBuilder { guild_id, query, limit, presences, user_ids } = self;
+        let query = match (query, &user_ids) {
+            (None, None) => Some(String::new()),
+            (query, _) => query,
+        };
+        let request = RequestGuildMembers { guild_id, query, limit, presences, user_ids };
+        request.validate()?;
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_members_by_default() {
+        let request = RequestGuildMembers::builder(GuildId::from(1)).build().unwrap();
+        assert_eq!(request.query.as_deref(), Some(""));
+        assert!(request.user_ids.is_none());
+    }
+
+    #[test]
+    fn guild_ids() {
+        assert!(RequestGuildMembers::builder(GuildId::from(1)).guild(GuildId::from(2)).build().is_ok());
+        let request = RequestGuildMembers { guild_id: Vec::new(), ..RequestGuildMembers::default() };
+        assert_eq!(request.validate(), Err(ValidationError::NoGuildIds));
+    }
+
+    #[test]
+    fn query_and_user_ids() {
+        let builder = || RequestGuildMembers::builder(GuildId::from(1));
+        assert!(builder().query("cor").build().is_ok());
+        let request = builder().user_ids(vec![UserId::from(2)]).build().unwrap();
+        assert!(request.query.is_none());
+        assert_eq!(
+            builder().query("cor").user_ids(vec![UserId::from(2)]).build().err(),
+            Some(ValidationError::QueryAndUserIds),
+        );
+    }
+
+    #[test]
+    fn user_id_count() {
+        let ids = |amount| (0..amount).map(UserId::from).collect::<Vec<_>>();
+        assert!(RequestGuildMembers::builder(GuildId::from(1)).user_ids(ids(100)).build().is_ok());
+        assert_eq!(
+            RequestGuildMembers::builder(GuildId::from(1)).user_ids(ids(101)).build().err(),
+            Some(ValidationError::TooManyUserIds(101)),
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use serde::Serialize;
 
+use super::ValidationError;
+
 #[derive(Serialize)]
 /// Used to replay missed events when a disconnected client resumes.
 pub struct Resume {
@@ -9,4 +11,55 @@ pub struct Resume {
     pub session_id: String,
     /// Last sequence number received.
     pub seq: u32,
-}
\ No newline at end of file
+}
+
+impl Resume {
+    /// Starts building a `Resume` that is validated when it is built.
+    pub fn builder(token: impl Into<String>, session_id: impl Into<String>) -> ResumeBuilder {
+        ResumeBuilder(Resume {
+            token: token.into(),
+            session_id: session_id.into(),
+            seq: 0,
+        })
+    }
+
+    /// Checks that Discord will accept this resume.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.token.is_empty() {
+            Err(ValidationError::EmptyToken)
+        } else if self.session_id.is_empty() {
+            Err(ValidationError::EmptySessionId)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Builds a [`Resume`](struct.Resume.html).
+pub struct ResumeBuilder(Resume);
+
+impl ResumeBuilder {
+    /// Sets the last sequence number received.
+    pub fn seq(mut self, seq: u32) -> Self {
+        self.0.seq = seq;
+        self
+    }
+
+    /// Validates and returns the resume.
+    pub fn build(self) -> Result<Resume, ValidationError> {
+        self.0.validate()?;
+        Ok(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_and_session_id() {
+        assert_eq!(Resume::builder("token", "session").seq(5).build().map(|resume| resume.seq), Ok(5));
+        assert_eq!(Resume::builder("", "session").build().err(), Some(ValidationError::EmptyToken));
+        assert_eq!(Resume::builder("token", "").build().err(), Some(ValidationError::EmptySessionId));
+    }
+}
@@ -0,0 +1,352 @@
+use std::fs::{self, File};
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Identity, ReceivedPayload, Resume, SendablePayload, Shard};
+use super::dispatch::DispatchEvent;
+
+/// Everything needed to resume a session, even after the process has restarted.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct SessionState {
+    /// The id of the session, given in the `Ready` event.
+    pub session_id: String,
+    /// The last sequence number received.
+    pub seq: u32,
+    /// The shard the session is for.
+    pub shard: Shard,
+    /// The gateway URL to reconnect to when resuming.
+    pub resume_gateway_url: Option<String>,
+}
+
+impl SessionState {
+    /// Creates the command used to resume this session.
+    pub fn resume(&self, token: impl Into<String>) -> Resume {
+        Resume {
+            token: token.into(),
+            session_id: self.session_id.clone(),
+            seq: self.seq,
+        }
+    }
+}
+
+/// Somewhere a [`SessionState`](struct.SessionState.html) can be kept between process restarts.
+pub trait SessionStore {
+    /// The error returned when the store could not be accessed.
+    type Error;
+
+    /// Loads the session of the shard, if one was saved.
+    fn load(&self, shard: Shard) -> Result<Option<SessionState>, Self::Error>;
+    /// Saves the session, replacing any previous session of the same shard.
+    fn save(&self, state: &SessionState) -> Result<(), Self::Error>;
+    /// Removes the session of the shard so that it is not resumed.
+    fn clear(&self, shard: Shard) -> Result<(), Self::Error>;
+}
+
+/// Stores each shard's session as a JSON file in a directory.
+pub struct FileSessionStore {
+    directory: PathBuf,
+}
+
+impl FileSessionStore {
+    /// Stores sessions in the given directory, which has to exist already.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: directory.into() }
+    }
+
+    fn path(&self, shard: Shard) -> PathBuf {
+        self.directory.join(format!("session-{}-{}.json", shard.shard_id, shard.num_shards))
+    }
+}
+
+impl SessionStore for FileSessionStore {
+    type Error = io::Error;
+
+    fn load(&self, shard: Shard) -> io::Result<Option<SessionState>> {
+        match File::open(self.path(shard)) {
+            Ok(file) => serde_json::from_reader(file).map(Some).map_err(io::Error::from),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn save(&self, state: &SessionState) -> io::Result<()> {
+        // Written to a temporary file and flushed to disk first so a crash can't leave half a session behind.
+        let path = self.path(state.shard);
+        let temporary = path.with_extension("json.tmp");
+        let mut file = File::create(&temporary)?;
+        serde_json::to_writer(&mut file, state)?;
+        file.sync_all()?;
+        fs::rename(temporary, path)
+    }
+
+    fn clear(&self, shard: Shard) -> io::Result<()> {
+        match fs::remove_file(self.path(shard)) {
+            Err(error) if error.kind() != ErrorKind::NotFound => Err(error),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Keeps track of a shard's session so it can be resumed instead of identifying again.
+///
+/// Every received payload should be passed to [`receive`](#method.receive).
+/// The session is saved when it starts, and should be saved with [`persist`](#method.persist)
+/// before the process exits so that the latest sequence number is resumed from.
+pub struct Session<S> {
+    store: S,
+    shard: Shard,
+    state: Option<SessionState>,
+}
+
+impl<S: SessionStore> Session<S> {
+    /// Loads the shard's previous session from the store, if there is one.
+    pub fn open(store: S, shard: Shard) -> Result<Self, S::Error> {
+        let state = store.load(shard)?;
+        Ok(Self { store, shard, state })
+    }
+
+    /// The current session, if there is one that can be resumed.
+    pub fn state(&self) -> Option<&SessionState> {
+        self.state.as_ref()
+    }
+
+    /// The command to send after connecting.
+    ///
+    /// This is a `Resume` if there is a session to resume, otherwise it is the given `Identity`.
+    pub fn handshake(&self, identity: Identity) -> SendablePayload {
+        match &self.state {
+            Some(state) => SendablePayload::Resume(state.resume(identity.token)),
+            None => SendablePayload::Identity(identity),
+        }
+    }
+
+    /// The heartbeat to send, containing the last sequence number received.
+    pub fn heartbeat(&self) -> SendablePayload {
+        SendablePayload::Heartbeat(self.state.as_ref().map(|state| state.seq))
+    }
+
+    /// Updates the session from a payload sent by the server.
+    ///
+    /// A new session is saved when `Ready` is received,
+    /// and the session is cleared when it is invalidated and can't be resumed.
+    ///
+    /// Events that are replayed after resuming but were already received are reported as
+    /// [`Duplicate`](enum.Sequence.html#variant.Duplicate) and should not be handled again.
+    pub fn receive(&mut self, payload: &ReceivedPayload) -> Result<Sequence, S::Error> {
+        match payload {
+            ReceivedPayload::Dispatch { seq, event: DispatchEvent::Ready(ready) } => {
+                let state = SessionState {
+                    session_id: ready.session_id.clone(),
+                    seq: *seq,
+                    shard: self.shard,
+                    resume_gateway_url: ready.resume_gateway_url.clone(),
+                };
+                self.store.save(&state)?;
+                self.state = Some(state);
+            },
+            ReceivedPayload::Dispatch { seq, .. } => if let Some(state) = &mut self.state {
+                let last = state.seq;
+                if *seq <= last {
+                    return Ok(Sequence::Duplicate { seq: *seq, last });
+                }
+                state.seq = *seq;
+                if *seq != last + 1 {
+                    return Ok(Sequence::Gap { expected: last + 1, received: *seq });
+                }
+            },
+            ReceivedPayload::InvalidSession(false) => {
+                self.state = None;
+                self.store.clear(self.shard)?;
+            },
+            _ => {},
+        }
+        Ok(Sequence::InOrder)
+    }
+
+    /// Saves the session with the last sequence number received.
+    pub fn persist(&self) -> Result<(), S::Error> {
+        match &self.state {
+            Some(state) => self.store.save(state),
+            None => self.store.clear(self.shard),
+        }
+    }
+}
+
+/// Where a received payload falls in the session's sequence of events.
+#[derive(Debug, PartialEq)]
+pub enum Sequence {
+    /// The payload follows the last event received and should be handled.
+    InOrder,
+    /// The event was already received and should be dropped.
+    Duplicate {
+        /// The sequence number of the event.
+        seq: u32,
+        /// The last sequence number received.
+        last: u32,
+    },
+    /// Events were missed between the last event received and this one.
+    ///
+    /// This event should still be handled.
+    Gap {
+        /// The sequence number that should have been received.
+        expected: u32,
+        /// The sequence number that was received instead.
+        received: u32,
+    },
+}
+
+impl Sequence {
+    /// Whether the payload should be handled, which is every payload except duplicates.
+    pub fn should_handle(&self) -> bool {
+        match self {
+            Sequence::Duplicate { .. } => false,
+            Sequence::InOrder | Sequence::Gap { .. } => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::convert::Infallible;
+
+    use serde_json::{from_str, json, to_value};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MemoryStore(RefCell<Option<SessionState>>);
+
+    impl SessionStore for &MemoryStore {
+        type Error = Infallible;
+
+        fn load(&self, _: Shard) -> Result<Option<SessionState>, Infallible> {
+            Ok(self.0.borrow().clone())
+        }
+
+        fn save(&self, state: &SessionState) -> Result<(), Infallible> {
+            *self.0.borrow_mut() = Some(state.clone());
+            Ok(())
+        }
+
+        fn clear(&self, _: Shard) -> Result<(), Infallible> {
+            *self.0.borrow_mut() = None;
+            Ok(())
+        }
+    }
+
+    fn dispatch(seq: u32) -> ReceivedPayload {
+        from_str(&format!(r#"{{"op":0,"s":{},"t":"MESSAGE_DELETE","d":{{"id":"{}","channel_id":"1"}}}}"#, seq, seq)).unwrap()
+    }
+
+    fn ready(seq: u32) -> ReceivedPayload {
+        from_str(&format!(r#"{{"op":0,"s":{},"t":"READY","d":{{
+            "v":6,"session_id":"abc","guilds":[],
+            "user":{{"id":"1","username":"cordis","discriminator":"0001"}}
+        }}}}"#, seq)).unwrap()
+    }
+
+    fn receive_all(session: &mut Session<&MemoryStore>, seqs: &[u32]) -> Vec<Sequence> {
+        seqs.iter().map(|seq| session.receive(&dispatch(*seq)).unwrap()).collect()
+    }
+
+    #[test]
+    fn resume_drops_overlapping_replay() {
+        let store = MemoryStore::default();
+        let mut session = Session::open(&store, Shard::default()).unwrap();
+        session.receive(&ready(1)).unwrap();
+        receive_all(&mut session, &[2, 3, 4]);
+        session.persist().unwrap();
+
+        let mut session = Session::open(&store, Shard::default()).unwrap();
+        match session.handshake(Identity::default()) {
+            SendablePayload::Resume(resume) => assert_eq!((resume.session_id.as_str(), resume.seq), ("abc", 4)),
+            _ => panic!("expected a resume"),
+        }
+        let replayed = receive_all(&mut session, &[3, 4, 5, 6]);
+        assert_eq!(replayed, vec![
+            Sequence::Duplicate { seq: 3, last: 4 },
+            Sequence::Duplicate { seq: 4, last: 4 },
+            Sequence::InOrder,
+            Sequence::InOrder,
+        ]);
+        assert_eq!(session.state().unwrap().seq, 6);
+    }
+
+    #[test]
+    fn gap_is_reported_and_handled() {
+        let store = MemoryStore::default();
+        let mut session = Session::open(&store, Shard::default()).unwrap();
+        session.receive(&ready(1)).unwrap();
+        let sequences = receive_all(&mut session, &[2, 5, 6]);
+        assert_eq!(sequences, vec![Sequence::InOrder, Sequence::Gap { expected: 3, received: 5 }, Sequence::InOrder]);
+        assert!(sequences.iter().all(Sequence::should_handle));
+    }
+
+    #[test]
+    fn new_session_resets_sequence() {
+        let store = MemoryStore::default();
+        let mut session = Session::open(&store, Shard::default()).unwrap();
+        session.receive(&ready(1)).unwrap();
+        receive_all(&mut session, &[2, 3]);
+        session.receive(&from_str(r#"{"op":9,"d":false}"#).unwrap()).unwrap();
+        assert!(store.0.borrow().is_none());
+        if let SendablePayload::Resume(_) = session.handshake(Identity::default()) {
+            panic!("expected an identity");
+        }
+        assert_eq!(session.receive(&ready(1)).unwrap(), Sequence::InOrder);
+        assert_eq!(receive_all(&mut session, &[2]), vec![Sequence::InOrder]);
+    }
+
+    fn state(seq: u32) -> SessionState {
+        SessionState {
+            session_id: "abc".to_string(),
+            seq,
+            shard: Shard { shard_id: 1, num_shards: 2 },
+            resume_gateway_url: Some("wss://gateway.discord.gg".to_string()),
+        }
+    }
+
+    #[test]
+    fn state_serialization() {
+        let value = to_value(state(4)).unwrap();
+        assert_eq!(value, json!({
+            "session_id": "abc", "seq": 4, "shard": [1, 2], "resume_gateway_url": "wss://gateway.discord.gg",
+        }));
+        let state: SessionState = serde_json::from_value(value).unwrap();
+        assert_eq!((state.session_id.as_str(), state.seq, state.shard.shard_id), ("abc", 4, 1));
+    }
+
+    #[test]
+    fn file_store() {
+        let directory = std::env::temp_dir().join(format!("cordis-session-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let store = FileSessionStore::new(&directory);
+        let shard = Shard { shard_id: 1, num_shards: 2 };
+
+        assert!(store.load(shard).unwrap().is_none());
+        store.save(&state(4)).unwrap();
+        store.save(&state(7)).unwrap();
+        assert_eq!(store.load(shard).unwrap().map(|state| state.seq), Some(7));
+        assert!(store.load(Shard::default()).unwrap().is_none());
+        assert!(!store.path(shard).with_extension("json.tmp").exists());
+
+        store.clear(shard).unwrap();
+        assert!(store.load(shard).unwrap().is_none());
+        store.clear(shard).unwrap();
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn last_sequence_number() {
+        let store = MemoryStore::default();
+        let mut session = Session::open(&store, Shard::default()).unwrap();
+        session.receive(&ready(u32::MAX - 1)).unwrap();
+        assert_eq!(receive_all(&mut session, &[u32::MAX, u32::MAX]), vec![
+            Sequence::InOrder,
+            Sequence::Duplicate { seq: u32::MAX, last: u32::MAX },
+        ]);
+    }
+}
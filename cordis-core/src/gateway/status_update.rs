@@ -1,42 +1,75 @@
 use serde::{Deserialize,Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use super::{Activity, ValidationError};
 
 #[derive(Default, Serialize)]
-/// Sent by the client to indicate a presence or status update. 
+/// Sent by the client to indicate a presence or status update.
 pub struct StatusUpdate {
     /// Unix time (in milliseconds) of when the client went idle.
     ///
     /// `None` if the client is not idle.
     pub since: Option<u64>,
-    /// The user's new activity.
-    ///
-    /// Or `None`.
-    pub game: Option<Activity>,
+    /// The user's new activities.
+    pub activities: Vec<Activity>,
     /// The user's new status.
     pub status: Status,
     /// Whether or not the client is AFK.
     pub afk: bool,
 }
 
-/// The user's activity.
-#[derive(Deserialize, Serialize)]
-pub struct Activity {
-    /// The activity's name.
-    pub name: String,
-    /// Activity Type.
-    #[serde(rename = "type")]
-    pub activity_type: ActivityType,
-    /// Stream URL.
-    ///
-    /// Is validated when type is 1.
-    pub url: Option<String>,
+impl StatusUpdate {
+    /// Starts building a `StatusUpdate` that is validated when it is built.
+    pub fn builder() -> StatusUpdateBuilder {
+        StatusUpdateBuilder(StatusUpdate::default())
+    }
+
+    /// Checks that Discord will accept this status update.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.activities.iter().try_for_each(Activity::validate)
+    }
+}
+
+/// Builds a [`StatusUpdate`](struct.StatusUpdate.html).
+pub struct StatusUpdateBuilder(StatusUpdate);
+
+impl StatusUpdateBuilder {
+    /// Sets when the client went idle, in Unix time (milliseconds).
+    pub fn since(mut self, since: u64) -> Self {
+        self.0.since = Some(since);
+        self
+    }
+
+    /// Adds an activity.
+    pub fn activity(mut self, activity: Activity) -> Self {
+        self.0.activities.push(activity);
+        self
+    }
+
+    /// Sets the user's new status.
+    pub fn status(mut self, status: Status) -> Self {
+        self.0.status = status;
+        self
+    }
+
+    /// Sets whether the client is AFK.
+    pub fn afk(mut self, afk: bool) -> Self {
+        self.0.afk = afk;
+        self
+    }
+
+    /// Validates and returns the status update.
+    pub fn build(self) -> Result<StatusUpdate, ValidationError> {
+        self.0.validate()?;
+        Ok(self.0)
+    }
 }
 
 /// The user's status.
-#[derive(Deserialize, Serialize)]
+#[derive(Default, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Status {
     /// Online.
+    #[default]
     Online,
     /// Do Not Disturb.
     Dnd,
@@ -47,26 +80,57 @@ pub enum Status {
     /// Offline.
     Offline,
 }
-impl Default for Status {
-    fn default() -> Self {
-        Status::Online
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json, to_value};
+
+    use super::*;
+    use super::super::ActivityType;
+
+    #[test]
+    fn streaming_url() {
+        for url in ["https://twitch.tv/cordis", "http://www.youtube.com/watch?v=1", "https://twitch.tv"] {
+            assert!(StatusUpdate::builder().activity(Activity::streaming("Cordis", url)).build().is_ok(), "{}", url);
+        }
+        for url in ["https://example.com/twitch.tv", "https://twitch.tv.example.com", "twitch.tv/cordis"] {
+            assert_eq!(
+                StatusUpdate::builder().activity(Activity::streaming("Cordis", url)).build().err(),
+                Some(ValidationError::StreamingUrl(Some(url.to_string()))),
+            );
+        }
+        let missing = Activity { activity_type: ActivityType::Streaming, ..Activity::playing("Cordis") };
+        assert_eq!(StatusUpdate::builder().activity(missing).build().err(), Some(ValidationError::StreamingUrl(None)));
     }
-}
 
-/// The type of activity the user is doing.
-#[derive(Deserialize_repr, Serialize_repr)]
-#[repr(u8)]
-pub enum ActivityType {
-    /// Playing {name}.
-    ///
-    /// e.g. "Playing Rocket League"
-    Game = 0,
-    /// Streaming {name}.
-    ///
-    /// e.g. "Streaming Rocker League"
-    Streaming = 1,
-    /// Listening to {name}.
-    ///
-    /// e.g. "Listening to Spotify"
-    Listening = 2,
+    #[test]
+    fn serialize_with_activities() {
+        let activities = vec![
+            Activity::streaming("Cordis", "https://twitch.tv/cordis"),
+            Activity { emoji: Some("😀".parse().unwrap()), ..Activity::custom("I am cool") },
+        ];
+        let update = activities.iter().cloned().fold(StatusUpdate::builder(), StatusUpdateBuilder::activity)
+            .since(1)
+            .status(Status::Idle)
+            .afk(true)
+            .build()
+            .unwrap();
+        let value = to_value(&update).unwrap();
+        assert_eq!(value, json!({
+            "since": 1,
+            "activities": [
+                { "name": "Cordis", "type": 1, "url": "https://twitch.tv/cordis" },
+                { "name": "Custom Status", "type": 4, "state": "I am cool", "emoji": { "id": null, "name": "😀" } },
+            ],
+            "status": "idle",
+            "afk": true,
+        }));
+        assert_eq!(from_value::<Vec<Activity>>(value["activities"].clone()).unwrap(), activities);
+        assert_eq!(to_value(StatusUpdate::default()).unwrap(), json!({ "since": null, "activities": [], "status": "online", "afk": false }));
+    }
+
+    #[test]
+    fn only_streaming_needs_url() {
+        assert!(StatusUpdate::builder().activity(Activity::playing("Cordis")).status(Status::Dnd).build().is_ok());
+    }
 }
@@ -0,0 +1,65 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// The reason a command could not be built because Discord would reject it.
+#[derive(Debug, PartialEq)]
+pub enum ValidationError {
+    /// The authentication token was empty.
+    EmptyToken,
+    /// The session id was empty.
+    EmptySessionId,
+    /// The `large_threshold` was not between 50 and 250.
+    LargeThreshold(u16),
+    /// The shard id was not less than the number of shards.
+    ShardOutOfRange {
+        /// The id of the shard.
+        shard_id: u16,
+        /// The number of shards.
+        num_shards: u16,
+    },
+    /// A `Streaming` activity did not have a Twitch or YouTube URL.
+    StreamingUrl(Option<String>),
+    /// No guilds were given to request members from.
+    NoGuildIds,
+    /// Both `query` and `user_ids` were given, only one of them can be sent.
+    QueryAndUserIds,
+    /// More than 100 user ids were requested at once.
+    TooManyUserIds(usize),
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            ValidationError::EmptyToken => write!(f, "the token can not be empty"),
+            ValidationError::EmptySessionId => write!(f, "the session id can not be empty"),
+            ValidationError::LargeThreshold(threshold) =>
+                write!(f, "`large_threshold` must be between 50 and 250 but was {}", threshold),
+            ValidationError::ShardOutOfRange { shard_id, num_shards } =>
+                write!(f, "shard id {} must be less than the number of shards ({})", shard_id, num_shards),
+            ValidationError::StreamingUrl(Some(url)) =>
+                write!(f, "`{}` is not a Twitch or YouTube URL which `Streaming` activities require", url),
+            ValidationError::StreamingUrl(None) => write!(f, "`Streaming` activities require a Twitch or YouTube URL"),
+            ValidationError::NoGuildIds => write!(f, "at least one guild id is required"),
+            ValidationError::QueryAndUserIds => write!(f, "`query` and `user_ids` can not be sent together"),
+            ValidationError::TooManyUserIds(amount) =>
+                write!(f, "at most 100 user ids can be requested but {} were given", amount),
+        }
+    }
+}
+
+impl Error for ValidationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(ValidationError::LargeThreshold(300).to_string(), "`large_threshold` must be between 50 and 250 but was 300");
+        assert_eq!(
+            ValidationError::ShardOutOfRange { shard_id: 2, num_shards: 2 }.to_string(),
+            "shard id 2 must be less than the number of shards (2)",
+        );
+        assert_eq!(ValidationError::StreamingUrl(None).to_string(), "`Streaming` activities require a Twitch or YouTube URL");
+    }
+}
@@ -1,14 +1,76 @@
 use serde::Serialize;
 
+use super::super::resources::{ChannelId, GuildId};
+
 /// Sent when a client wants to join, move, or disconnect from a voice channel.
 #[derive(Serialize)]
 pub struct VoiceStateUpdate {
     /// Id of the guild.
-    pub guid_id: String,
+    pub guild_id: GuildId,
     /// Id of the channel client wants to join (none if disconnecting).
-    pub channel_id: Option<String>,
+    pub channel_id: Option<ChannelId>,
     /// Is the client muted?
     pub self_mute: bool,
     /// Is the client deafened?
     pub self_deaf: bool,
 }
+
+impl VoiceStateUpdate {
+    /// Starts building a `VoiceStateUpdate` which disconnects from voice unless a channel is given.
+    pub fn builder(guild_id: GuildId) -> VoiceStateUpdateBuilder {
+        VoiceStateUpdateBuilder(VoiceStateUpdate {
+            guild_id,
+            channel_id: None,
+            self_mute: false,
+            self_deaf: false,
+        })
+    }
+}
+
+/// Builds a [`VoiceStateUpdate`](struct.VoiceStateUpdate.html).
+///
+/// Every combination of fields is accepted by Discord so building can not fail.
+pub struct VoiceStateUpdateBuilder(VoiceStateUpdate);
+
+impl VoiceStateUpdateBuilder {
+    /// Sets the channel to join or move to.
+    pub fn channel(mut self, channel_id: ChannelId) -> Self {
+        self.0.channel_id = Some(channel_id);
+        self
+    }
+
+    /// Sets whether the client is muted.
+    pub fn self_mute(mut self, self_mute: bool) -> Self {
+        self.0.self_mute = self_mute;
+        self
+    }
+
+    /// Sets whether the client is deafened.
+    pub fn self_deaf(mut self, self_deaf: bool) -> Self {
+        self.0.self_deaf = self_deaf;
+        self
+    }
+
+    /// Returns the voice state update.
+    pub fn build(self) -> VoiceStateUpdate {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn disconnects_without_channel() {
+        let update = VoiceStateUpdate::builder(GuildId::from(1)).self_mute(true).build();
+        assert_eq!(
+            to_value(&update).unwrap(),
+            json!({ "guild_id": "1", "channel_id": null, "self_mute": true, "self_deaf": false }),
+        );
+        let update = VoiceStateUpdate::builder(GuildId::from(1)).channel(ChannelId::from(2)).build();
+        assert_eq!(to_value(&update).unwrap()["channel_id"], "2");
+    }
+}
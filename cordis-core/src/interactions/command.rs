@@ -0,0 +1,201 @@
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use super::super::resources::{ApplicationId, channel::ChannelType, CommandId, GuildId};
+
+/// A command users can run from Discord's client, like a slash command.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/interactions/application-commands#application-command-object)
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ApplicationCommand {
+    /// The id of the command, `None` if it hasn't been registered yet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<CommandId>,
+    /// The type of command.
+    #[serde(rename = "type", default)]
+    pub kind: CommandType,
+    /// The id of the application the command belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_id: Option<ApplicationId>,
+    /// The guild the command is in, `None` if it is global.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// The name of the command.
+    ///
+    /// (1-32 characters)
+    pub name: String,
+    /// The description of the command, empty for user and message commands.
+    ///
+    /// (1-100 characters)
+    pub description: String,
+    /// The parameters of the command.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<CommandOption>,
+}
+
+impl ApplicationCommand {
+    /// A slash command that hasn't been registered yet.
+    pub fn chat_input(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self::new(CommandType::ChatInput, name.into(), description.into())
+    }
+
+    /// A command shown when right clicking a user, that hasn't been registered yet.
+    pub fn user(name: impl Into<String>) -> Self {
+        Self::new(CommandType::User, name.into(), String::new())
+    }
+
+    /// A command shown when right clicking a message, that hasn't been registered yet.
+    pub fn message(name: impl Into<String>) -> Self {
+        Self::new(CommandType::Message, name.into(), String::new())
+    }
+
+    fn new(kind: CommandType, name: String, description: String) -> Self {
+        Self { id: None, kind, application_id: None, guild_id: None, name, description, options: Vec::new() }
+    }
+
+    /// Adds a parameter or subcommand.
+    pub fn option(mut self, option: CommandOption) -> Self {
+        self.options.push(option);
+        self
+    }
+}
+
+/// The type of an application command.
+#[derive(Clone, Copy, Debug, Default, Deserialize_repr, Eq, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum CommandType {
+    /// A slash command.
+    #[default]
+    ChatInput = 1,
+    /// Shown when right clicking a user.
+    User = 2,
+    /// Shown when right clicking a message.
+    Message = 3,
+}
+
+/// A parameter or subcommand of an application command.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CommandOption {
+    /// The type of the option.
+    #[serde(rename = "type")]
+    pub kind: CommandOptionType,
+    /// The name of the option.
+    ///
+    /// (1-32 characters)
+    pub name: String,
+    /// The description of the option.
+    ///
+    /// (1-100 characters)
+    pub description: String,
+    /// Whether the option has to be given, which can only be set on parameters.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+    /// The values the user has to choose from.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<CommandOptionChoice>,
+    /// The parameters of a subcommand, or the subcommands of a group.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<CommandOption>,
+    /// The types of channel that can be chosen, if this is a channel option.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_types: Vec<ChannelType>,
+    /// Whether the choices are suggested by the application as the user types.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub autocomplete: bool,
+}
+
+impl CommandOption {
+    /// An option of the type that isn't required and has no choices.
+    pub fn new(kind: CommandOptionType, name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+            description: description.into(),
+            required: false,
+            choices: Vec::new(),
+            options: Vec::new(),
+            channel_types: Vec::new(),
+            autocomplete: false,
+        }
+    }
+
+    /// Sets whether the option has to be given.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Adds a value the user can choose.
+    pub fn choice(mut self, name: impl Into<String>, value: impl Into<ChoiceValue>) -> Self {
+        self.choices.push(CommandOptionChoice { name: name.into(), value: value.into() });
+        self
+    }
+
+    /// Adds a parameter to a subcommand, or a subcommand to a group.
+    pub fn option(mut self, option: CommandOption) -> Self {
+        self.options.push(option);
+        self
+    }
+}
+
+/// The type of a command option.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum CommandOptionType {
+    SubCommand = 1,
+    SubCommandGroup = 2,
+    String = 3,
+    Integer = 4,
+    Boolean = 5,
+    User = 6,
+    Channel = 7,
+    Role = 8,
+    /// A user or a role.
+    Mentionable = 9,
+    Number = 10,
+    Attachment = 11,
+}
+
+/// A value the user can choose for an option.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CommandOptionChoice {
+    /// The name shown to the user.
+    pub name: String,
+    /// The value sent to the application.
+    pub value: ChoiceValue,
+}
+
+/// The value of a [`CommandOptionChoice`](struct.CommandOptionChoice.html).
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ChoiceValue {
+    Integer(i64),
+    Number(f64),
+    String(String),
+}
+
+impl From<i64> for ChoiceValue {
+    fn from(value: i64) -> Self {
+        ChoiceValue::Integer(value)
+    }
+}
+
+impl From<f64> for ChoiceValue {
+    fn from(value: f64) -> Self {
+        ChoiceValue::Number(value)
+    }
+}
+
+impl From<&str> for ChoiceValue {
+    fn from(value: &str) -> Self {
+        ChoiceValue::String(value.to_string())
+    }
+}
+
+impl From<String> for ChoiceValue {
+    fn from(value: String) -> Self {
+        ChoiceValue::String(value)
+    }
+}
@@ -0,0 +1,397 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{de::Error as DeError, Deserialize, Deserializer};
+use serde_json::Value;
+use serde_repr::Deserialize_repr;
+
+use super::command::{CommandOptionType, CommandType};
+use super::super::resources::{
+    ApplicationId, Attachment, AttachmentId, ChannelId, channel::ChannelType, CommandId, Component, ComponentType,
+    GuildId, InteractionId, Message, MessageId, Permissions, Role, RoleId, Snowflake, TopLevelComponent, User, UserId,
+};
+
+/// Sent by Discord when a user uses a command or component of the application.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/interactions/receiving-and-responding#interaction-object)
+pub struct Interaction {
+    /// The id of the interaction.
+    pub id: InteractionId,
+    /// The id of the application the interaction is for.
+    pub application_id: ApplicationId,
+    /// The type of interaction.
+    pub kind: InteractionType,
+    /// What the user did, `None` for pings.
+    pub data: Option<InteractionData>,
+    /// The guild it was sent from, `None` in direct messages.
+    pub guild_id: Option<GuildId>,
+    /// The channel it was sent from.
+    pub channel_id: Option<ChannelId>,
+    /// The member that sent it, only sent in guilds.
+    pub member: Option<InteractionMember>,
+    /// The user that sent it, only sent in direct messages.
+    pub user: Option<User>,
+    /// The token used to respond to the interaction, which lasts 15 minutes.
+    pub token: String,
+    /// Always `1`.
+    pub version: u8,
+    /// The message the component is attached to, for component interactions.
+    pub message: Option<Message>,
+}
+
+impl Interaction {
+    /// The user that sent the interaction, whether it was sent in a guild or not.
+    pub fn author(&self) -> Option<&User> {
+        self.member.as_ref()
+            .and_then(|member| member.user.as_ref())
+            .or(self.user.as_ref())
+    }
+}
+
+/// The type of an interaction.
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, PartialEq)]
+#[repr(u8)]
+pub enum InteractionType {
+    /// Sent by Discord to check the endpoint is up.
+    Ping = 1,
+    /// A user used an application command.
+    ApplicationCommand = 2,
+    /// A user used a component on a message.
+    MessageComponent = 3,
+    /// A user is typing an option that has autocomplete enabled.
+    ApplicationCommandAutocomplete = 4,
+    /// A user submitted a modal.
+    ModalSubmit = 5,
+}
+
+/// What the user did to send the interaction.
+#[allow(clippy::large_enum_variant)]
+pub enum InteractionData {
+    /// The command used, for command and autocomplete interactions.
+    Command(CommandData),
+    /// The component used, for component interactions.
+    Component(ComponentData),
+    /// The values of the text inputs, for modal submit interactions.
+    ModalSubmit(ModalSubmitData),
+    /// The data of an interaction type that isn't supported yet.
+    Unknown(Value),
+}
+
+/// The command a user used and the options they gave.
+#[derive(Deserialize)]
+pub struct CommandData {
+    /// The id of the command.
+    pub id: CommandId,
+    /// The name of the command.
+    pub name: String,
+    /// The type of the command.
+    #[serde(rename = "type")]
+    pub kind: CommandType,
+    /// The users, roles, channels and messages the options refer to.
+    #[serde(default)]
+    pub resolved: Resolved,
+    /// The options given, or the subcommand used.
+    #[serde(default)]
+    pub options: Vec<CommandDataOption>,
+    /// The user or message the command was used on, for user and message commands.
+    pub target_id: Option<Snowflake>,
+}
+
+/// The component a user used on a message.
+#[derive(Deserialize)]
+pub struct ComponentData {
+    /// The `custom_id` of the component.
+    pub custom_id: String,
+    /// The type of the component.
+    pub component_type: ComponentType,
+    /// The values of the options chosen, for select menus.
+    #[serde(default)]
+    pub values: Vec<String>,
+}
+
+/// The form a user submitted.
+#[derive(Deserialize)]
+pub struct ModalSubmitData {
+    /// The `custom_id` of the modal.
+    pub custom_id: String,
+    /// The rows of the form, with the `value` of each text input set to what the user wrote.
+    pub components: Vec<TopLevelComponent>,
+}
+
+impl ModalSubmitData {
+    /// What the user wrote in the text input with the `custom_id`.
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        self.components.iter()
+            .filter_map(TopLevelComponent::as_action_row)
+            .flat_map(|row| &row.components)
+            .find_map(|component| match component {
+                Component::TextInput(input) if input.custom_id == custom_id => input.value.as_deref(),
+                _ => None,
+            })
+    }
+}
+
+/// An option given by the user.
+#[derive(Deserialize)]
+pub struct CommandDataOption {
+    /// The name of the option.
+    pub name: String,
+    /// The type of the option.
+    #[serde(rename = "type")]
+    pub kind: CommandOptionType,
+    /// The value given, `None` for subcommands and groups.
+    pub value: Option<OptionValue>,
+    /// The options of the subcommand, or the subcommand of the group.
+    #[serde(default)]
+    pub options: Vec<CommandDataOption>,
+    /// Whether the user is typing this option, for autocomplete interactions.
+    #[serde(default)]
+    pub focused: bool,
+}
+
+/// The value of an option given by the user.
+///
+/// Users, channels, roles and attachments are sent as strings holding their ids,
+/// which can be looked up in [`Resolved`](struct.Resolved.html).
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum OptionValue {
+    Boolean(bool),
+    Integer(i64),
+    Number(f64),
+    String(String),
+}
+
+impl OptionValue {
+    /// The value as a string, if it is one.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            OptionValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            OptionValue::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The value as a number, which includes integers.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            OptionValue::Integer(value) => Some(*value as f64),
+            OptionValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The value as a boolean, if it is one.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            OptionValue::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The id held by a user, channel, role or attachment option.
+    pub fn as_snowflake(&self) -> Option<Snowflake> {
+        self.as_str()?.parse().ok()
+    }
+}
+
+/// The full objects of the ids given in options, keyed by their ids.
+#[derive(Default, Deserialize)]
+pub struct Resolved {
+    /// The users given.
+    #[serde(default)]
+    pub users: HashMap<UserId, User>,
+    /// The members of the users given, when used in a guild.
+    #[serde(default)]
+    pub members: HashMap<UserId, InteractionMember>,
+    /// The roles given.
+    #[serde(default)]
+    pub roles: HashMap<RoleId, Role>,
+    /// The channels given.
+    #[serde(default)]
+    pub channels: HashMap<ChannelId, PartialChannel>,
+    /// The message a message command was used on.
+    #[serde(default)]
+    pub messages: HashMap<MessageId, Message>,
+    /// The files given.
+    #[serde(default)]
+    pub attachments: HashMap<AttachmentId, Attachment>,
+}
+
+/// A guild member sent with an interaction.
+#[derive(Deserialize)]
+pub struct InteractionMember {
+    /// The user of the member, not sent in [`Resolved`](struct.Resolved.html) as it is in `users`.
+    pub user: Option<User>,
+    /// The guild nickname of the member (if one is set).
+    pub nick: Option<String>,
+    /// The roles of the member.
+    pub roles: Vec<RoleId>,
+    /// When the member joined the guild.
+    pub joined_at: DateTime<Utc>,
+    /// When the member used their Nitro boost on the guild.
+    pub premium_since: Option<DateTime<Utc>>,
+    /// The permissions of the member in the channel, including overwrites.
+    pub permissions: Option<Permissions>,
+}
+
+/// A channel given in an option.
+#[derive(Deserialize)]
+pub struct PartialChannel {
+    /// The id of the channel.
+    pub id: ChannelId,
+    /// The name of the channel.
+    pub name: Option<String>,
+    /// The type of the channel.
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// The permissions of the member in the channel, including overwrites.
+    pub permissions: Option<Permissions>,
+}
+
+#[derive(Deserialize)]
+struct InitialInteraction {
+    id: InteractionId,
+    application_id: ApplicationId,
+    #[serde(rename = "type")]
+    kind: InteractionType,
+    data: Option<Value>,
+    guild_id: Option<GuildId>,
+    channel_id: Option<ChannelId>,
+    member: Option<InteractionMember>,
+    user: Option<User>,
+    token: String,
+    version: u8,
+    message: Option<Message>,
+}
+
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let initial = InitialInteraction::deserialize(d)?;
+        let data = match (initial.kind, initial.data) {
+            (_, None) => None,
+            (InteractionType::ApplicationCommand, Some(data)) | (InteractionType::ApplicationCommandAutocomplete, Some(data)) => {
+                Some(InteractionData::Command(CommandData::deserialize(data).map_err(D::Error::custom)?))
+            },
+            (InteractionType::MessageComponent, Some(data)) => {
+                Some(InteractionData::Component(ComponentData::deserialize(data).map_err(D::Error::custom)?))
+            },
+            (InteractionType::ModalSubmit, Some(data)) => {
+                Some(InteractionData::ModalSubmit(ModalSubmitData::deserialize(data).map_err(D::Error::custom)?))
+            },
+            (_, Some(data)) => Some(InteractionData::Unknown(data)),
+        };
+        Ok(Interaction {
+            id: initial.id,
+            application_id: initial.application_id,
+            kind: initial.kind,
+            data,
+            guild_id: initial.guild_id,
+            channel_id: initial.channel_id,
+            member: initial.member,
+            user: initial.user,
+            token: initial.token,
+            version: initial.version,
+            message: initial.message,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_interaction() {
+        let interaction: Interaction = serde_json::from_str(r#"{
+            "id": "786008729715212338",
+            "application_id": "775799577604522054",
+            "type": 2,
+            "data": {
+                "id": "771825006014889984",
+                "name": "ban",
+                "type": 1,
+                "options": [
+                    {"name": "user", "type": 6, "value": "53908232506183680"},
+                    {"name": "days", "type": 4, "value": 7}
+                ],
+                "resolved": {
+                    "users": {
+                        "53908232506183680": {"id": "53908232506183680", "username": "Mason", "discriminator": "1337", "avatar": null}
+                    },
+                    "members": {
+                        "53908232506183680": {"nick": null, "roles": ["539082325061836999"], "joined_at": "2017-03-13T19:19:14.040000+00:00", "permissions": "2147483647"}
+                    }
+                }
+            },
+            "guild_id": "290926798626357999",
+            "channel_id": "645027906669510667",
+            "member": {
+                "user": {"id": "53908232506183681", "username": "Kim", "discriminator": "0001", "avatar": null},
+                "roles": [],
+                "joined_at": "2017-03-13T19:19:14.040000+00:00",
+                "permissions": "8"
+            },
+            "token": "A_UNIQUE_TOKEN",
+            "version": 1
+        }"#).unwrap();
+        assert_eq!(interaction.kind, InteractionType::ApplicationCommand);
+        assert_eq!(interaction.author().unwrap().username, "Kim");
+        let data = match interaction.data {
+            Some(InteractionData::Command(data)) => data,
+            _ => panic!("expected command data"),
+        };
+        assert_eq!(data.name, "ban");
+        let user_id = data.options[0].value.as_ref().and_then(OptionValue::as_snowflake).map(UserId::from).unwrap();
+        assert_eq!(data.resolved.users[&user_id].username, "Mason");
+        assert_eq!(data.resolved.members[&user_id].permissions, Some(Permissions::from_bits_retain(2147483647)));
+        assert_eq!(data.options[1].value.as_ref().and_then(OptionValue::as_i64), Some(7));
+    }
+
+    #[test]
+    fn ping() {
+        let interaction: Interaction = serde_json::from_str(
+            r#"{"id": "1", "application_id": "2", "type": 1, "token": "t", "version": 1}"#
+        ).unwrap();
+        assert_eq!(interaction.kind, InteractionType::Ping);
+        assert!(interaction.data.is_none());
+    }
+
+    #[test]
+    fn component_interactions() {
+        let interaction: Interaction = serde_json::from_str(r#"{
+            "id": "1", "application_id": "2", "type": 3, "token": "t", "version": 1,
+            "data": {"custom_id": "colour", "component_type": 3, "values": ["red"]}
+        }"#).unwrap();
+        match interaction.data {
+            Some(InteractionData::Component(data)) => {
+                assert_eq!(data.custom_id, "colour");
+                assert_eq!(data.component_type, ComponentType::SelectMenu);
+                assert_eq!(data.values, vec!["red"]);
+            },
+            _ => panic!("expected component data"),
+        }
+        let interaction: Interaction = serde_json::from_str(r#"{
+            "id": "1", "application_id": "2", "type": 5, "token": "t", "version": 1,
+            "data": {"custom_id": "feedback", "components": [
+                {"type": 1, "components": [{"type": 4, "custom_id": "name", "value": "Mason"}]}
+            ]}
+        }"#).unwrap();
+        match interaction.data {
+            Some(InteractionData::ModalSubmit(data)) => {
+                assert_eq!(data.custom_id, "feedback");
+                assert_eq!(data.value("name"), Some("Mason"));
+                assert_eq!(data.value("missing"), None);
+            },
+            _ => panic!("expected modal submit data"),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Types for application commands and the interactions Discord sends when they're used.
+//!
+//! [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/interactions/receiving-and-responding)
+
+mod command;
+pub use command::{ApplicationCommand, ChoiceValue, CommandOption, CommandOptionChoice, CommandOptionType, CommandType};
+mod interaction;
+pub use interaction::{
+    CommandData, CommandDataOption, ComponentData, Interaction, InteractionData, InteractionMember, InteractionType,
+    ModalSubmitData, OptionValue, PartialChannel, Resolved,
+};
+mod response;
+pub use response::{CallbackMessage, EPHEMERAL, InteractionCallbackData, InteractionCallbackType, InteractionResponse, Modal};
+mod sync;
+pub use sync::{plan_sync, SyncPlan};
+mod verify;
+pub use verify::{SIGNATURE_HEADER, TIMESTAMP_HEADER, Verifier, VerifyError};
@@ -0,0 +1,271 @@
+use serde::Serialize;
+use serde_repr::Serialize_repr;
+
+use super::command::CommandOptionChoice;
+use super::super::resources::{
+    ActionRow, AllowedMentions, ComponentError, Embed, MAX_MODAL_TITLE_LENGTH, TopLevelComponent, validate_components,
+    validate_custom_id, validate_modal_components,
+};
+
+/// The message flag that makes a message only visible to the user that sent the interaction.
+pub const EPHEMERAL: u64 = 1 << 6;
+
+/// The response to an interaction, sent as the body of the reply to Discord's request.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/interactions/receiving-and-responding#interaction-response-object)
+#[derive(Clone, Debug, Serialize)]
+pub struct InteractionResponse {
+    /// The type of response.
+    #[serde(rename = "type")]
+    pub kind: InteractionCallbackType,
+    /// The message, choices or modal to respond with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<InteractionCallbackData>,
+}
+
+impl InteractionResponse {
+    /// Acknowledges a ping.
+    pub fn pong() -> Self {
+        Self { kind: InteractionCallbackType::Pong, data: None }
+    }
+
+    /// Responds with a message.
+    pub fn message(message: CallbackMessage) -> Self {
+        Self { kind: InteractionCallbackType::ChannelMessageWithSource, data: Some(InteractionCallbackData::Message(message)) }
+    }
+
+    /// Shows the user a loading state, the message has to be sent later by editing the original response.
+    pub fn deferred(ephemeral: bool) -> Self {
+        let data = if ephemeral {
+            Some(InteractionCallbackData::Message(CallbackMessage::new().ephemeral(true)))
+        } else {
+            None
+        };
+        Self { kind: InteractionCallbackType::DeferredChannelMessageWithSource, data }
+    }
+
+    /// Acknowledges a component interaction, the message can be edited later.
+    pub fn deferred_update() -> Self {
+        Self { kind: InteractionCallbackType::DeferredUpdateMessage, data: None }
+    }
+
+    /// Edits the message the component is attached to.
+    pub fn update(message: CallbackMessage) -> Self {
+        Self { kind: InteractionCallbackType::UpdateMessage, data: Some(InteractionCallbackData::Message(message)) }
+    }
+
+    /// Shows the user a form to fill in, which can't be the response to a modal being submitted.
+    pub fn modal(modal: Modal) -> Self {
+        Self { kind: InteractionCallbackType::Modal, data: Some(InteractionCallbackData::Modal(modal)) }
+    }
+
+    /// Suggests choices for the option the user is typing.
+    pub fn autocomplete(choices: Vec<CommandOptionChoice>) -> Self {
+        Self {
+            kind: InteractionCallbackType::ApplicationCommandAutocompleteResult,
+            data: Some(InteractionCallbackData::Autocomplete { choices }),
+        }
+    }
+}
+
+/// The type of an interaction response.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum InteractionCallbackType {
+    /// Acknowledges a ping.
+    Pong = 1,
+    /// Responds with a message.
+    ChannelMessageWithSource = 4,
+    /// Acknowledges the interaction and shows a loading state.
+    DeferredChannelMessageWithSource = 5,
+    /// Acknowledges a component interaction without showing a loading state.
+    DeferredUpdateMessage = 6,
+    /// Edits the message the component is attached to.
+    UpdateMessage = 7,
+    /// Suggests choices for an option.
+    ApplicationCommandAutocompleteResult = 8,
+    /// Shows the user a form to fill in.
+    Modal = 9,
+}
+
+/// What is sent with an interaction response.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum InteractionCallbackData {
+    /// A message to send or edit.
+    Message(CallbackMessage),
+    /// Choices for the option the user is typing.
+    Autocomplete {
+        /// The choices, of which there can be up to 25.
+        choices: Vec<CommandOptionChoice>,
+    },
+    /// A form to show the user.
+    Modal(Modal),
+}
+
+/// A message sent in response to an interaction.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CallbackMessage {
+    /// Whether this is a TTS message.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub tts: bool,
+    /// The content of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Embedded rich content.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub embeds: Vec<Embed>,
+    /// Which mentions in the content are allowed to notify anyone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+    /// The flags of the message, of which only [`EPHEMERAL`](constant.EPHEMERAL.html) can be set.
+    #[serde(skip_serializing_if = "is_zero")]
+    pub flags: u64,
+    /// The buttons and select menus attached to the message.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<TopLevelComponent>,
+}
+
+fn is_zero(flags: &u64) -> bool {
+    *flags == 0
+}
+
+impl CallbackMessage {
+    /// An empty message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the content of the message.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Sets whether this is a TTS message.
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.tts = tts;
+        self
+    }
+
+    /// Adds an embed.
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embeds.push(embed);
+        self
+    }
+
+    /// Sets which mentions in the content are allowed to notify anyone.
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    /// Sets whether the message is only visible to the user that sent the interaction.
+    pub fn ephemeral(mut self, ephemeral: bool) -> Self {
+        if ephemeral {
+            self.flags |= EPHEMERAL;
+        } else {
+            self.flags &= !EPHEMERAL;
+        }
+        self
+    }
+
+    /// Adds a row of components.
+    pub fn action_row(mut self, row: ActionRow) -> Self {
+        self.components.push(row.into());
+        self
+    }
+
+    /// Checks that Discord will accept the components of the message.
+    pub fn validate(&self) -> Result<(), ComponentError> {
+        validate_components(&self.components)
+    }
+}
+
+/// A form of text inputs shown to the user, which sends a modal submit interaction when submitted.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal)
+#[derive(Clone, Debug, Serialize)]
+pub struct Modal {
+    /// The id sent with the interaction.
+    pub custom_id: String,
+    /// The title of the form.
+    pub title: String,
+    /// The rows of the form, each with one text input.
+    pub components: Vec<TopLevelComponent>,
+}
+
+impl Modal {
+    /// A form with no text inputs.
+    pub fn new(custom_id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self { custom_id: custom_id.into(), title: title.into(), components: Vec::new() }
+    }
+
+    /// Adds a row holding a text input.
+    pub fn action_row(mut self, row: ActionRow) -> Self {
+        self.components.push(row.into());
+        self
+    }
+
+    /// Checks that Discord will accept the form.
+    pub fn validate(&self) -> Result<(), ComponentError> {
+        let length = self.title.chars().count();
+        if length == 0 || length > MAX_MODAL_TITLE_LENGTH {
+            return Err(ComponentError::InvalidTitleLength(length));
+        }
+        validate_custom_id(&self.custom_id)?;
+        validate_modal_components(&self.components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+    use super::super::super::resources::{Button, ButtonStyle, MentionType, TextInput, TextInputStyle};
+
+    #[test]
+    fn message() {
+        let message = CallbackMessage::new()
+            .content("Pick one")
+            .allowed_mentions(AllowedMentions::default().parse(MentionType::Users))
+            .ephemeral(true)
+            .action_row(ActionRow::new().component(Button::new(ButtonStyle::Success, "yes", "Yes")));
+        assert_eq!(to_value(InteractionResponse::update(message)).unwrap(), json!({
+            "type": 7,
+            "data": {
+                "content": "Pick one",
+                "allowed_mentions": {"parse": ["users"]},
+                "flags": 64,
+                "components": [{"type": 1, "components": [{"type": 2, "style": 3, "label": "Yes", "custom_id": "yes"}]}]
+            }
+        }));
+        assert_eq!(to_value(InteractionResponse::deferred(false)).unwrap(), json!({"type": 5}));
+        assert_eq!(to_value(InteractionResponse::deferred(true)).unwrap(), json!({"type": 5, "data": {"flags": 64}}));
+    }
+
+    #[test]
+    fn modal() {
+        let modal = Modal::new("feedback", "Feedback")
+            .action_row(ActionRow::new().component(TextInput::new("text", TextInputStyle::Paragraph, "What do you think?").required(false)));
+        assert_eq!(modal.validate(), Ok(()));
+        assert_eq!(to_value(InteractionResponse::modal(modal)).unwrap(), json!({
+            "type": 9,
+            "data": {
+                "custom_id": "feedback",
+                "title": "Feedback",
+                "components": [{"type": 1, "components": [
+                    {"type": 4, "custom_id": "text", "style": 2, "label": "What do you think?", "required": false}
+                ]}]
+            }
+        }));
+    }
+
+    #[test]
+    fn modal_title() {
+        assert_eq!(Modal::new("id", "é".repeat(45)).validate(), Ok(()));
+        assert_eq!(Modal::new("id", "é".repeat(46)).validate(), Err(ComponentError::InvalidTitleLength(46)));
+        assert_eq!(Modal::new("id", "").validate(), Err(ComponentError::InvalidTitleLength(0)));
+    }
+}
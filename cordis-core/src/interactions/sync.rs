@@ -0,0 +1,136 @@
+use super::command::{ApplicationCommand, CommandOption};
+use super::super::resources::CommandId;
+
+/// The requests needed to make the registered commands match the declared ones.
+#[derive(Debug, Default, PartialEq)]
+pub struct SyncPlan<'a> {
+    /// Declared commands that aren't registered yet.
+    pub create: Vec<&'a ApplicationCommand>,
+    /// Registered commands that differ from their declaration, with the id to edit.
+    pub update: Vec<(CommandId, &'a ApplicationCommand)>,
+    /// Registered commands that are no longer declared.
+    pub delete: Vec<CommandId>,
+}
+
+impl SyncPlan<'_> {
+    /// Whether the registered commands already match.
+    pub fn is_empty(&self) -> bool {
+        self.create.is_empty() && self.update.is_empty() && self.delete.is_empty()
+    }
+}
+
+/// Compares the declared commands with those registered in the same scope, either globally or in one guild.
+///
+/// Commands are matched by their type and name, as those are unique within a scope.
+/// Options and choices are compared in order, as that is the order users see them in.
+/// Values Discord leaves out when they are the default, like `required: false`, count as not being set.
+///
+/// Registered commands without an id are ignored.
+pub fn plan_sync<'a>(declared: &'a [ApplicationCommand], registered: &[ApplicationCommand]) -> SyncPlan<'a> {
+    let mut plan = SyncPlan::default();
+    for command in declared {
+        let existing = registered.iter()
+            .find(|existing| existing.id.is_some() && existing.kind == command.kind && existing.name == command.name);
+        match existing {
+            None => plan.create.push(command),
+            Some(existing) if !same_command(command, existing) => {
+                plan.update.push((existing.id.expect("Could not find the id of a registered command"), command))
+            },
+            Some(_) => {},
+        }
+    }
+    plan.delete = registered.iter()
+        .filter(|existing| !declared.iter().any(|command| command.kind == existing.kind && command.name == existing.name))
+        .filter_map(|existing| existing.id)
+        .collect();
+    plan
+}
+
+fn same_command(a: &ApplicationCommand, b: &ApplicationCommand) -> bool {
+    a.kind == b.kind && a.name == b.name && a.description == b.description && same_options(&a.options, &b.options)
+}
+
+fn same_options(a: &[CommandOption], b: &[CommandOption]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same_option(a, b))
+}
+
+fn same_option(a: &CommandOption, b: &CommandOption) -> bool {
+    a.kind == b.kind
+        && a.name == b.name
+        && a.description == b.description
+        && a.required == b.required
+        && a.autocomplete == b.autocomplete
+        && a.choices == b.choices
+        && a.channel_types.len() == b.channel_types.len()
+        && a.channel_types.iter().all(|kind| b.channel_types.contains(kind))
+        && same_options(&a.options, &b.options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::command::CommandOptionType;
+    use super::*;
+
+    fn registered(id: u64, command: ApplicationCommand) -> ApplicationCommand {
+        ApplicationCommand { id: Some(id.into()), ..command }
+    }
+
+    fn ban() -> ApplicationCommand {
+        ApplicationCommand::chat_input("ban", "Bans a user")
+            .option(CommandOption::new(CommandOptionType::User, "user", "Who to ban").required(true))
+            .option(CommandOption::new(CommandOptionType::Integer, "days", "Days of messages to delete").choice("One", 1).choice("Seven", 7))
+    }
+
+    #[test]
+    fn plans_changes() {
+        let declared = [ban(), ApplicationCommand::chat_input("ping", "Pong!"), ApplicationCommand::user("Info")];
+        let existing = [
+            registered(1, ApplicationCommand::chat_input("ping", "Ping!")),
+            registered(2, ApplicationCommand::chat_input("kick", "Kicks a user")),
+            registered(3, ApplicationCommand::user("Info")),
+        ];
+        let plan = plan_sync(&declared, &existing);
+        assert_eq!(plan.create, vec![&declared[0]]);
+        assert_eq!(plan.update, vec![(CommandId::from(1), &declared[1])]);
+        assert_eq!(plan.delete, vec![CommandId::from(2)]);
+    }
+
+    #[test]
+    fn matches_by_type_and_name() {
+        let declared = [ApplicationCommand::message("Info")];
+        let plan = plan_sync(&declared, &[registered(1, ApplicationCommand::user("Info"))]);
+        assert_eq!(plan.create, vec![&declared[0]]);
+        assert_eq!(plan.delete, vec![CommandId::from(1)]);
+    }
+
+    #[test]
+    fn ignores_defaults() {
+        let existing: ApplicationCommand = serde_json::from_str(r#"{
+            "id": "1", "application_id": "2", "type": 1, "name": "ban", "description": "Bans a user",
+            "options": [
+                {"type": 6, "name": "user", "description": "Who to ban", "required": true},
+                {"type": 4, "name": "days", "description": "Days of messages to delete", "required": false,
+                 "choices": [{"name": "One", "value": 1}, {"name": "Seven", "value": 7}]}
+            ]
+        }"#).unwrap();
+        assert!(plan_sync(&[ban()], &[existing]).is_empty());
+    }
+
+    #[test]
+    fn option_order_matters() {
+        let mut reordered = ban();
+        reordered.options.reverse();
+        let declared = [ban()];
+        let plan = plan_sync(&declared, &[registered(1, reordered)]);
+        assert_eq!(plan.update, vec![(CommandId::from(1), &declared[0])]);
+    }
+
+    #[test]
+    fn choice_order_matters() {
+        let mut reordered = ban();
+        reordered.options[1].choices.reverse();
+        let declared = [ban()];
+        let plan = plan_sync(&declared, &[registered(1, reordered)]);
+        assert_eq!(plan.update, vec![(CommandId::from(1), &declared[0])]);
+    }
+}
@@ -0,0 +1,126 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use ed25519_dalek::{Signature, Verifier as _, VerifyingKey};
+
+/// The header holding the hex encoded signature of a request.
+pub const SIGNATURE_HEADER: &str = "X-Signature-Ed25519";
+/// The header holding the timestamp that was signed with the body of a request.
+pub const TIMESTAMP_HEADER: &str = "X-Signature-Timestamp";
+
+/// Checks that interaction requests were sent by Discord, using the public key of the application.
+///
+/// Discord requires endpoints to reject requests with a bad signature with a `401 Unauthorized`.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/interactions/receiving-and-responding#security-and-authorization)
+#[derive(Clone, Debug)]
+pub struct Verifier(VerifyingKey);
+
+impl Verifier {
+    /// Creates a verifier from the hex encoded public key shown in the developer portal.
+    pub fn new(public_key: &str) -> Result<Self, VerifyError> {
+        let bytes = decode_hex::<32>(public_key.trim()).ok_or(VerifyError::InvalidPublicKey)?;
+        VerifyingKey::from_bytes(&bytes)
+            .map(Verifier)
+            .map_err(|_| VerifyError::InvalidPublicKey)
+    }
+
+    /// Checks the signature and timestamp headers of a request against its body.
+    pub fn verify(&self, signature: &str, timestamp: &str, body: &[u8]) -> Result<(), VerifyError> {
+        let signature = decode_hex::<64>(signature.trim()).ok_or(VerifyError::MalformedSignature)?;
+        let signature = Signature::from_bytes(&signature);
+        let mut message = Vec::with_capacity(timestamp.len() + body.len());
+        message.extend_from_slice(timestamp.as_bytes());
+        message.extend_from_slice(body);
+        self.0.verify(&message, &signature).map_err(|_| VerifyError::BadSignature)
+    }
+}
+
+/// Why a request could not be verified.
+#[derive(Debug, PartialEq)]
+pub enum VerifyError {
+    /// The public key is not 32 hex encoded bytes of a valid key.
+    InvalidPublicKey,
+    /// The signature is not 64 hex encoded bytes.
+    MalformedSignature,
+    /// The signature doesn't match the timestamp and body.
+    BadSignature,
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            VerifyError::InvalidPublicKey => write!(f, "the public key is not a valid hex encoded Ed25519 key"),
+            VerifyError::MalformedSignature => write!(f, "the signature is not a hex encoded Ed25519 signature"),
+            VerifyError::BadSignature => write!(f, "the signature does not match the request"),
+        }
+    }
+}
+
+impl Error for VerifyError {}
+
+fn decode_hex<const N: usize>(hex: &str) -> Option<[u8; N]> {
+    let hex = hex.as_bytes();
+    if hex.len() != N * 2 {
+        return None;
+    }
+    let mut bytes = [0; N];
+    for (byte, pair) in bytes.iter_mut().zip(hex.chunks(2)) {
+        let digit = |c: u8| (c as char).to_digit(16);
+        *byte = (digit(pair[0])? * 16 + digit(pair[1])?) as u8;
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{Signer, SigningKey};
+    use rand::rngs::OsRng;
+
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    fn sign(key: &SigningKey, timestamp: &str, body: &str) -> String {
+        hex(&key.sign(format!("{}{}", timestamp, body).as_bytes()).to_bytes())
+    }
+
+    #[test]
+    fn valid_signature() {
+        let key = SigningKey::generate(&mut OsRng);
+        let verifier = Verifier::new(&hex(key.verifying_key().as_bytes())).unwrap();
+        let signature = sign(&key, "1600000000", r#"{"type":1}"#);
+        assert_eq!(verifier.verify(&signature, "1600000000", br#"{"type":1}"#), Ok(()));
+        assert_eq!(verifier.verify(&signature.to_uppercase(), "1600000000", br#"{"type":1}"#), Ok(()));
+    }
+
+    #[test]
+    fn tampered_request() {
+        let key = SigningKey::generate(&mut OsRng);
+        let verifier = Verifier::new(&hex(key.verifying_key().as_bytes())).unwrap();
+        let signature = sign(&key, "1600000000", r#"{"type":1}"#);
+        assert_eq!(verifier.verify(&signature, "1600000000", br#"{"type":2}"#), Err(VerifyError::BadSignature));
+        assert_eq!(verifier.verify(&signature, "1600000001", br#"{"type":1}"#), Err(VerifyError::BadSignature));
+    }
+
+    #[test]
+    fn wrong_key() {
+        let key = SigningKey::generate(&mut OsRng);
+        let other = SigningKey::generate(&mut OsRng);
+        let verifier = Verifier::new(&hex(other.verifying_key().as_bytes())).unwrap();
+        let signature = sign(&key, "1600000000", "{}");
+        assert_eq!(verifier.verify(&signature, "1600000000", b"{}"), Err(VerifyError::BadSignature));
+    }
+
+    #[test]
+    fn malformed_input() {
+        assert_eq!(Verifier::new("not hex").unwrap_err(), VerifyError::InvalidPublicKey);
+        assert_eq!(Verifier::new(&"ab".repeat(31)).unwrap_err(), VerifyError::InvalidPublicKey);
+        let key = SigningKey::generate(&mut OsRng);
+        let verifier = Verifier::new(&hex(key.verifying_key().as_bytes())).unwrap();
+        assert_eq!(verifier.verify("zz", "1600000000", b"{}"), Err(VerifyError::MalformedSignature));
+        assert_eq!(verifier.verify(&"g0".repeat(64), "1600000000", b"{}"), Err(VerifyError::MalformedSignature));
+    }
+}
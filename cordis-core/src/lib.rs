@@ -2,5 +2,10 @@
 //! This crate provides types that can be reused among multiple different Discord frameworks,
 //! without making any assumptions about what type of websocket crate or implementation is being used.
 
+pub mod cdn;
 pub mod gateway;
+pub mod interactions;
+pub mod markdown;
+pub mod oauth2;
+mod percent_encoding;
 pub mod resources;
@@ -0,0 +1,49 @@
+/// Characters that start or end formatting.
+const FORMATTING: &[char] = &['\\', '*', '_', '~', '`', '|', '>', '[', ']'];
+
+/// Makes untrusted text safe to echo back in a message.
+///
+/// Formatting characters are escaped with a backslash so they show up as typed,
+/// and `@everyone`, `@here` and role mentions are broken up so that they don't ping anyone.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for (index, c) in text.char_indices() {
+        let rest = &text[index..];
+        if FORMATTING.contains(&c) || rest.starts_with("<@&") {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+        if rest.starts_with("@everyone") || rest.starts_with("@here") {
+            // A zero width space stops Discord from recognising the mention.
+            escaped.push('\u{200B}');
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{parse, Node};
+
+    #[test]
+    fn round_trip() {
+        let inputs = [
+            "**bold** *italic* __under__ ~~struck~~ ||spoiler||",
+            "`code` ```block```",
+            "> quote\n>>> quote",
+            "[link](https://discord.com)",
+            r"back\slash \*",
+            "plain text 😀",
+        ];
+        for input in inputs.iter() {
+            assert_eq!(parse(&escape(input)), vec![Node::Text(input.to_string())], "{}", input);
+        }
+    }
+
+    #[test]
+    fn mentions_do_not_ping() {
+        assert_eq!(escape("@everyone @here"), "@\u{200B}everyone @\u{200B}here");
+        assert_eq!(escape("<@&1> <@2>"), r"\<@&1\> <@2\>");
+    }
+}
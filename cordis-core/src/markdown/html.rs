@@ -0,0 +1,102 @@
+use super::Node;
+
+/// Renders parsed message content as HTML.
+///
+/// Text is escaped, spoilers become `<span class="spoiler">`
+/// and code blocks with a language get a `language-` class for syntax highlighters.
+pub fn to_html(nodes: &[Node]) -> String {
+    let mut html = String::new();
+    render(nodes, &mut html);
+    html
+}
+
+fn render(nodes: &[Node], html: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => push_escaped(text, html, true),
+            Node::Bold(children) => wrap("strong", children, html),
+            Node::Italic(children) => wrap("em", children, html),
+            Node::Underline(children) => wrap("u", children, html),
+            Node::Strikethrough(children) => wrap("s", children, html),
+            Node::Spoiler(children) => {
+                html.push_str("<span class=\"spoiler\">");
+                render(children, html);
+                html.push_str("</span>");
+            },
+            Node::InlineCode(code) => {
+                html.push_str("<code>");
+                push_escaped(code, html, false);
+                html.push_str("</code>");
+            },
+            Node::CodeBlock { language, code } => {
+                match language {
+                    Some(language) => {
+                        html.push_str("<pre><code class=\"language-");
+                        push_escaped(language, html, false);
+                        html.push_str("\">");
+                    },
+                    None => html.push_str("<pre><code>"),
+                }
+                push_escaped(code, html, false);
+                html.push_str("</code></pre>");
+            },
+            Node::BlockQuote(children) => wrap("blockquote", children, html),
+            Node::Link { text, url } => {
+                html.push_str("<a href=\"");
+                push_escaped(url, html, false);
+                html.push_str("\">");
+                render(text, html);
+                html.push_str("</a>");
+            },
+        }
+    }
+}
+
+fn wrap(tag: &str, children: &[Node], html: &mut String) {
+    html.push_str(&format!("<{}>", tag));
+    render(children, html);
+    html.push_str(&format!("</{}>", tag));
+}
+
+fn push_escaped(text: &str, html: &mut String, line_breaks: bool) {
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            '\n' if line_breaks => html.push_str("<br>"),
+            c => html.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::parse;
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(to_html(&parse("<b>&amp;</b> \"'")), "&lt;b&gt;&amp;amp;&lt;/b&gt; &quot;&#39;");
+        assert_eq!(to_html(&parse("`<script>`")), "<code>&lt;script&gt;</code>");
+        assert_eq!(
+            to_html(&parse("```html\"><x>\n<p>a & b</p>\n```")),
+            "<pre><code>html&quot;&gt;&lt;x&gt;\n&lt;p&gt;a &amp; b&lt;/p&gt;</code></pre>",
+        );
+        assert_eq!(
+            to_html(&parse("[a](https://x.com/?a=1&b=\"2\")")),
+            "<a href=\"https://x.com/?a=1&amp;b=&quot;2&quot;\">a</a>",
+        );
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(
+            to_html(&parse("**a** _b_ __c__ ~~d~~ ||e||\n> f")),
+            "<strong>a</strong> <em>b</em> <u>c</u> <s>d</s> <span class=\"spoiler\">e</span><br><blockquote>f</blockquote>",
+        );
+        assert_eq!(to_html(&parse("```rust\nfn main() {}\n```")), "<pre><code class=\"language-rust\">fn main() {}</code></pre>");
+    }
+}
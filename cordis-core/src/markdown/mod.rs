@@ -0,0 +1,45 @@
+//! Parses, renders and escapes the flavour of markdown used in Discord messages.
+//!
+//! [See the official Discord documentation for more information.](https://support.discordapp.com/hc/en-us/articles/210298617-Markdown-Text-101-Chat-Formatting-Bold-Italic-Underline-)
+
+mod escape;
+pub use escape::escape;
+mod html;
+pub use html::to_html;
+mod parser;
+pub use parser::parse;
+
+/// A piece of formatted message content.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Node {
+    /// Plain text, with any escaping backslashes removed.
+    Text(String),
+    /// `**bold**`.
+    Bold(Vec<Node>),
+    /// `*italics*` or `_italics_`.
+    Italic(Vec<Node>),
+    /// `__underline__`.
+    Underline(Vec<Node>),
+    /// `~~strikethrough~~`.
+    Strikethrough(Vec<Node>),
+    /// `||spoiler||`.
+    Spoiler(Vec<Node>),
+    /// `` `inline code` ``, which isn't formatted any further.
+    InlineCode(String),
+    /// A fenced code block, which isn't formatted any further.
+    CodeBlock {
+        /// The language given after the opening fence, used for syntax highlighting.
+        language: Option<String>,
+        /// The code inside the block.
+        code: String,
+    },
+    /// Lines starting with `> `, or everything after `>>> `.
+    BlockQuote(Vec<Node>),
+    /// `[text](https://example.com)`, only rendered by Discord in embeds and webhook messages.
+    Link {
+        /// The text shown instead of the URL.
+        text: Vec<Node>,
+        /// The URL linked to.
+        url: String,
+    },
+}
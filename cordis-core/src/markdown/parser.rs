@@ -0,0 +1,265 @@
+use super::Node;
+
+/// Parses message content into formatted nodes.
+///
+/// Like Discord, anything that isn't valid formatting is kept as text.
+pub fn parse(content: &str) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut rest = content;
+    while !rest.is_empty() {
+        if let Some(quote) = rest.strip_prefix(">>> ") {
+            nodes.push(Node::BlockQuote(parse_inline(quote)));
+            break;
+        }
+        if rest.starts_with("> ") {
+            let mut lines = Vec::new();
+            while let Some(line) = rest.strip_prefix("> ").filter(|_| !rest.starts_with(">>> ")) {
+                let end = line.find('\n').map_or(line.len(), |end| end + 1);
+                lines.push(line[..end].trim_end_matches('\n'));
+                rest = &line[end..];
+            }
+            nodes.push(Node::BlockQuote(parse_inline(&lines.join("\n"))));
+            continue;
+        }
+        let end = paragraph_end(rest);
+        nodes.extend(parse_inline(&rest[..end]));
+        rest = &rest[end..];
+    }
+    nodes
+}
+
+/// Finds where the next block quote starts, ignoring any inside code blocks.
+fn paragraph_end(text: &str) -> usize {
+    let mut fences = 0;
+    let mut start = 0;
+    for line in text.split_inclusive('\n') {
+        let quote = line.starts_with("> ") || line.starts_with(">>> ");
+        if start > 0 && fences % 2 == 0 && quote {
+            return start;
+        }
+        fences += line.matches("```").count();
+        start += line.len();
+    }
+    text.len()
+}
+
+fn parse_inline(text: &str) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut plain = String::new();
+    let mut index = 0;
+    while let Some(c) = text[index..].chars().next() {
+        let rest = &text[index..];
+        if c == '\\' {
+            if let Some(escaped) = rest[1..].chars().next().filter(char::is_ascii_punctuation) {
+                plain.push(escaped);
+                index += 1 + escaped.len_utf8();
+                continue;
+            }
+        }
+        let word_before = text[..index].chars().next_back().is_some_and(char::is_alphanumeric);
+        if let Some((node, length)) = parse_node(rest, word_before) {
+            if !plain.is_empty() {
+                nodes.push(Node::Text(std::mem::take(&mut plain)));
+            }
+            nodes.push(node);
+            index += length;
+        } else {
+            plain.push(c);
+            index += c.len_utf8();
+        }
+    }
+    if !plain.is_empty() {
+        nodes.push(Node::Text(plain));
+    }
+    nodes
+}
+
+/// Parses the formatting at the start of the text, returning it with the length of its syntax.
+fn parse_node(text: &str, word_before: bool) -> Option<(Node, usize)> {
+    let first = text.chars().next()?;
+    match first {
+        '`' => code_block(text).or_else(|| inline_code(text)),
+        '|' => wrapped(text, "||", |inner| find(inner, "||", None)).map(|(inner, length)| (Node::Spoiler(parse_inline(inner)), length)),
+        '~' => wrapped(text, "~~", |inner| find(inner, "~~", None)).map(|(inner, length)| (Node::Strikethrough(parse_inline(inner)), length)),
+        '*' => wrapped(text, "**", |inner| find(inner, "**", Some('*')))
+            .map(|(inner, length)| (Node::Bold(parse_inline(inner)), length))
+            .or_else(|| wrapped(text, "*", star_italic_end).map(|(inner, length)| (Node::Italic(parse_inline(inner)), length))),
+        '_' => wrapped(text, "__", |inner| find(inner, "__", Some('_')))
+            .map(|(inner, length)| (Node::Underline(parse_inline(inner)), length))
+            .or_else(|| if word_before {
+                None
+            } else {
+                wrapped(text, "_", underscore_italic_end).map(|(inner, length)| (Node::Italic(parse_inline(inner)), length))
+            }),
+        '[' => link(text),
+        _ => None,
+    }
+}
+
+/// Splits the text into the content between the delimiters and the total length, if it is closed.
+fn wrapped<'a>(text: &'a str, delimiter: &str, end: impl Fn(&str) -> Option<usize>) -> Option<(&'a str, usize)> {
+    let inner = text.strip_prefix(delimiter)?;
+    let end = end(inner)?;
+    Some((&inner[..end], delimiter.len() * 2 + end))
+}
+
+/// Finds the first unescaped delimiter after at least one character,
+/// which can't be followed by `not_followed_by` so that the delimiter closes at the end of a run.
+fn find(text: &str, delimiter: &str, not_followed_by: Option<char>) -> Option<usize> {
+    let mut chars = text.char_indices().skip(1);
+    while let Some((index, c)) = chars.next() {
+        if c == '\\' {
+            chars.next();
+        } else if text[index..].starts_with(delimiter) {
+            let after = text[index + delimiter.len()..].chars().next();
+            if not_followed_by.is_none_or(|c| after != Some(c)) {
+                return Some(index);
+            }
+        }
+    }
+    None
+}
+
+/// Finds the end of `*italics*`, which can contain `**bold**` but no other stars
+/// and can't start or end with whitespace.
+fn star_italic_end(text: &str) -> Option<usize> {
+    if text.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let mut chars = text.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => { chars.next(); },
+            '*' if text[index..].starts_with("**") => { chars.next(); },
+            '*' if index == 0 || text[..index].ends_with(char::is_whitespace) => return None,
+            '*' => return Some(index),
+            _ => {},
+        }
+    }
+    None
+}
+
+/// Finds the end of `_italics_`, which has to be followed by the end of a word.
+fn underscore_italic_end(text: &str) -> Option<usize> {
+    let mut chars = text.char_indices().skip(1);
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => { chars.next(); },
+            '_' if text[index..].starts_with("__") => { chars.next(); },
+            '_' if !text[index + 1..].starts_with(char::is_alphanumeric) => return Some(index),
+            _ => {},
+        }
+    }
+    None
+}
+
+fn code_block(text: &str) -> Option<(Node, usize)> {
+    let inner = text.strip_prefix("```")?;
+    let end = inner.find("```")?;
+    let content = &inner[..end];
+    let (language, code) = match content.find('\n') {
+        Some(newline) if is_language(&content[..newline]) => (Some(content[..newline].to_string()), &content[newline + 1..]),
+        Some(0) => (None, &content[1..]),
+        _ => (None, content),
+    };
+    if code.trim().is_empty() && language.is_none() {
+        return None;
+    }
+    let code = code.strip_suffix('\n').unwrap_or(code).to_string();
+    Some((Node::CodeBlock { language, code }, end + 6))
+}
+
+fn is_language(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_ascii_alphanumeric() || "+-_.#".contains(c))
+}
+
+fn inline_code(text: &str) -> Option<(Node, usize)> {
+    let ticks = text.len() - text.trim_start_matches('`').len();
+    let delimiter = &text[..ticks];
+    let inner = &text[ticks..];
+    let mut search = 0;
+    while let Some(found) = inner[search..].find(delimiter) {
+        let end = search + found;
+        let run = inner[end..].len() - inner[end..].trim_start_matches('`').len();
+        if end > 0 && run == ticks {
+            return Some((Node::InlineCode(inner[..end].to_string()), end + ticks * 2));
+        }
+        search = end + run;
+    }
+    None
+}
+
+fn link(text: &str) -> Option<(Node, usize)> {
+    let inner = text.strip_prefix('[')?;
+    let text_end = find(inner, "](", None)?;
+    let url_start = text_end + 2;
+    let url_length = inner[url_start..].find(')')?;
+    let url = &inner[url_start..url_start + url_length];
+    let valid_url = (url.starts_with("https://") || url.starts_with("http://")) && !url.contains(char::is_whitespace);
+    if !valid_url {
+        return None;
+    }
+    let node = Node::Link { text: parse_inline(&inner[..text_end]), url: url.to_string() };
+    Some((node, 1 + url_start + url_length + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> Node {
+        Node::Text(text.to_string())
+    }
+
+    #[test]
+    fn nesting() {
+        assert_eq!(parse("**bold _italic ~~struck~~_** __under ||spoiler||__"), vec![
+            Node::Bold(vec![text("bold "), Node::Italic(vec![text("italic "), Node::Strikethrough(vec![text("struck")])])]),
+            text(" "),
+            Node::Underline(vec![text("under "), Node::Spoiler(vec![text("spoiler")])]),
+        ]);
+        assert_eq!(parse("***both***"), vec![Node::Bold(vec![Node::Italic(vec![text("both")])])]);
+        assert_eq!(parse("*a **b** c*"), vec![Node::Italic(vec![text("a "), Node::Bold(vec![text("b")]), text(" c")])]);
+    }
+
+    #[test]
+    fn unclosed_delimiters() {
+        for content in ["**bold", "*italic", "__under", "~~struck", "||spoiler", "`code", "```code", "[link](https://a"] {
+            assert_eq!(parse(content), vec![text(content)], "{}", content);
+        }
+        assert_eq!(parse("* not italic *"), vec![text("* not italic *")]);
+        assert_eq!(parse("snake_case_name"), vec![text("snake_case_name")]);
+        assert_eq!(parse("**open *italic*"), vec![text("**open "), Node::Italic(vec![text("italic")])]);
+        assert_eq!(parse("*a * b*"), vec![text("*a * b*")]);
+    }
+
+    #[test]
+    fn code_suppresses_formatting() {
+        assert_eq!(parse("`**not bold**` and ``a ` tick``"), vec![
+            Node::InlineCode("**not bold**".to_string()),
+            text(" and "),
+            Node::InlineCode("a ` tick".to_string()),
+        ]);
+        assert_eq!(parse("```rust\nlet x = *y * z;\n```"), vec![
+            Node::CodeBlock { language: Some("rust".to_string()), code: "let x = *y * z;".to_string() },
+        ]);
+        assert_eq!(parse("```\n> not a quote\n```"), vec![
+            Node::CodeBlock { language: None, code: "> not a quote".to_string() },
+        ]);
+    }
+
+    #[test]
+    fn quotes_and_links() {
+        assert_eq!(parse("> one\n> two\nafter"), vec![Node::BlockQuote(vec![text("one\ntwo")]), text("after")]);
+        assert_eq!(parse(">>> all\nof this"), vec![Node::BlockQuote(vec![text("all\nof this")])]);
+        assert_eq!(parse("[**docs**](https://discord.com)"), vec![
+            Node::Link { text: vec![Node::Bold(vec![text("docs")])], url: "https://discord.com".to_string() },
+        ]);
+        assert_eq!(parse("[docs](javascript:alert)"), vec![text("[docs](javascript:alert)")]);
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(parse(r"\*\*not bold\*\* \\"), vec![text(r"**not bold** \")]);
+    }
+}
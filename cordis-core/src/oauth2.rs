@@ -0,0 +1,436 @@
+//! Types used to authorize users and bots with Discord's OAuth2 flow.
+//!
+//! [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/topics/oauth2)
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_repr::Deserialize_repr;
+
+use super::percent_encoding::percent_encode;
+use super::resources::{ApplicationId, GuildId, Permissions, TeamId, User, UserId, Webhook};
+
+/// The URL users are sent to, to authorize an application.
+pub const AUTHORIZE_URL: &str = "https://discord.com/api/oauth2/authorize";
+/// The URL that codes and refresh tokens are exchanged for access tokens at.
+pub const TOKEN_URL: &str = "https://discord.com/api/oauth2/token";
+
+/// What an application is allowed to do on behalf of a user.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Scope {
+    /// Adds the application's bot to a guild.
+    Bot,
+    /// Allows `/users/@me` without an email.
+    Identify,
+    /// Allows `/users/@me` to return an email.
+    Email,
+    /// Allows `/users/@me/guilds` to return the user's guilds.
+    Guilds,
+    /// Allows `/guilds/{guild.id}/members/{user.id}` to be used to join users to a guild.
+    GuildsJoin,
+    /// Allows `/users/@me/connections` to return linked third-party accounts.
+    Connections,
+    /// Allows the application to join users to a group DM.
+    GdmJoin,
+    /// Lets the application create commands in a guild.
+    ApplicationsCommands,
+    /// Creates a webhook that is returned in the token response.
+    WebhookIncoming,
+    /// A scope that isn't known yet.
+    Other(String),
+}
+
+impl Scope {
+    /// The name of the scope used in URLs and token responses.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Scope::Bot => "bot",
+            Scope::Identify => "identify",
+            Scope::Email => "email",
+            Scope::Guilds => "guilds",
+            Scope::GuildsJoin => "guilds.join",
+            Scope::Connections => "connections",
+            Scope::GdmJoin => "gdm.join",
+            Scope::ApplicationsCommands => "applications.commands",
+            Scope::WebhookIncoming => "webhook.incoming",
+            Scope::Other(scope) => scope,
+        }
+    }
+}
+
+impl From<&str> for Scope {
+    fn from(scope: &str) -> Self {
+        match scope {
+            "bot" => Scope::Bot,
+            "identify" => Scope::Identify,
+            "email" => Scope::Email,
+            "guilds" => Scope::Guilds,
+            "guilds.join" => Scope::GuildsJoin,
+            "connections" => Scope::Connections,
+            "gdm.join" => Scope::GdmJoin,
+            "applications.commands" => Scope::ApplicationsCommands,
+            "webhook.incoming" => Scope::WebhookIncoming,
+            scope => Scope::Other(scope.to_string()),
+        }
+    }
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Whether the user is asked to authorize the application again if they already have.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Prompt {
+    /// Always ask the user.
+    Consent,
+    /// Skip asking the user if they have already authorized the application.
+    None,
+}
+
+/// Why an authorization URL could not be built.
+#[derive(Debug, PartialEq)]
+pub enum AuthorizeUrlError {
+    /// No scopes were requested.
+    NoScopes,
+    /// A redirect URI is needed for every scope except `bot` and `applications.commands`.
+    MissingRedirectUri,
+}
+
+impl Display for AuthorizeUrlError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            AuthorizeUrlError::NoScopes => write!(f, "at least one scope has to be requested"),
+            AuthorizeUrlError::MissingRedirectUri => write!(f, "a redirect URI is needed for scopes other than bot and applications.commands"),
+        }
+    }
+}
+
+impl Error for AuthorizeUrlError {}
+
+/// Builds the URL that a user visits to authorize an application, or to add its bot to a guild.
+pub struct AuthorizeUrl {
+    client_id: ApplicationId,
+    scopes: Vec<Scope>,
+    permissions: Option<Permissions>,
+    guild_id: Option<GuildId>,
+    redirect_uri: Option<String>,
+    state: Option<String>,
+    prompt: Option<Prompt>,
+}
+
+impl AuthorizeUrl {
+    /// Starts building the authorization URL of the application.
+    pub fn new(client_id: ApplicationId) -> Self {
+        Self {
+            client_id,
+            scopes: Vec::new(),
+            permissions: None,
+            guild_id: None,
+            redirect_uri: None,
+            state: None,
+            prompt: None,
+        }
+    }
+
+    /// Requests a scope.
+    pub fn scope(mut self, scope: Scope) -> Self {
+        if !self.scopes.contains(&scope) {
+            self.scopes.push(scope);
+        }
+        self
+    }
+
+    /// Sets the permissions the bot is given when it is added to a guild.
+    pub fn permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions = Some(permissions);
+        self
+    }
+
+    /// Selects the guild the bot is added to.
+    pub fn guild_id(mut self, guild_id: GuildId) -> Self {
+        self.guild_id = Some(guild_id);
+        self
+    }
+
+    /// Sets where the user is sent with the code after authorizing the application.
+    pub fn redirect_uri(mut self, redirect_uri: impl Into<String>) -> Self {
+        self.redirect_uri = Some(redirect_uri.into());
+        self
+    }
+
+    /// Sets a value that is sent back with the code, used to prevent CSRF.
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// Sets whether the user is asked again if they already authorized the application.
+    pub fn prompt(mut self, prompt: Prompt) -> Self {
+        self.prompt = Some(prompt);
+        self
+    }
+
+    /// Checks the parameters and returns the URL.
+    pub fn build(self) -> Result<String, AuthorizeUrlError> {
+        if self.scopes.is_empty() {
+            return Err(AuthorizeUrlError::NoScopes);
+        }
+        // Adding a bot to a guild doesn't redirect the user back.
+        let bot_only = self.scopes.iter().all(|scope| matches!(scope, Scope::Bot | Scope::ApplicationsCommands));
+        let mut url = format!("{}?client_id={}", AUTHORIZE_URL, self.client_id);
+        match &self.redirect_uri {
+            Some(redirect_uri) => url.push_str(&format!("&response_type=code&redirect_uri={}", percent_encode(redirect_uri))),
+            None if !bot_only => return Err(AuthorizeUrlError::MissingRedirectUri),
+            None => {},
+        }
+        let scopes: Vec<String> = self.scopes.iter().map(|scope| percent_encode(scope.as_str())).collect();
+        url.push_str(&format!("&scope={}", scopes.join("%20")));
+        if let Some(permissions) = self.permissions {
+            url.push_str(&format!("&permissions={}", permissions.bits()));
+        }
+        if let Some(guild_id) = self.guild_id {
+            url.push_str(&format!("&guild_id={}", guild_id));
+        }
+        if let Some(state) = &self.state {
+            url.push_str(&format!("&state={}", percent_encode(state)));
+        }
+        match self.prompt {
+            Some(Prompt::Consent) => url.push_str("&prompt=consent"),
+            Some(Prompt::None) => url.push_str("&prompt=none"),
+            None => {},
+        }
+        Ok(url)
+    }
+}
+
+/// The form sent to [`TOKEN_URL`](constant.TOKEN_URL.html) to get an access token.
+#[derive(Clone, Debug, Serialize)]
+pub struct TokenRequest {
+    /// The id of the application.
+    pub client_id: ApplicationId,
+    /// The secret of the application.
+    pub client_secret: String,
+    /// What the access token is granted with.
+    #[serde(flatten)]
+    pub grant: Grant,
+}
+
+/// What an access token is granted with.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "grant_type", rename_all = "snake_case")]
+pub enum Grant {
+    /// The code the user was redirected with after authorizing the application.
+    AuthorizationCode {
+        /// The code from the redirect.
+        code: String,
+        /// The redirect URI used in the authorization URL.
+        redirect_uri: String,
+    },
+    /// The refresh token of an access token that expired.
+    RefreshToken {
+        /// The refresh token.
+        refresh_token: String,
+    },
+}
+
+impl TokenRequest {
+    /// Exchanges the code the user was redirected with for an access token.
+    pub fn authorization_code(
+        client_id: ApplicationId, client_secret: impl Into<String>, code: impl Into<String>, redirect_uri: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id,
+            client_secret: client_secret.into(),
+            grant: Grant::AuthorizationCode { code: code.into(), redirect_uri: redirect_uri.into() },
+        }
+    }
+
+    /// Exchanges a refresh token for a new access token.
+    pub fn refresh(client_id: ApplicationId, client_secret: impl Into<String>, refresh_token: impl Into<String>) -> Self {
+        Self {
+            client_id,
+            client_secret: client_secret.into(),
+            grant: Grant::RefreshToken { refresh_token: refresh_token.into() },
+        }
+    }
+
+    /// The request as an `application/x-www-form-urlencoded` body, which is what Discord expects.
+    pub fn to_form(&self) -> String {
+        let mut pairs = vec![("client_id", self.client_id.to_string()), ("client_secret", self.client_secret.clone())];
+        match &self.grant {
+            Grant::AuthorizationCode { code, redirect_uri } => {
+                pairs.push(("grant_type", "authorization_code".to_string()));
+                pairs.push(("code", code.clone()));
+                pairs.push(("redirect_uri", redirect_uri.clone()));
+            },
+            Grant::RefreshToken { refresh_token } => {
+                pairs.push(("grant_type", "refresh_token".to_string()));
+                pairs.push(("refresh_token", refresh_token.clone()));
+            },
+        }
+        let pairs: Vec<String> = pairs.iter().map(|(key, value)| format!("{}={}", key, percent_encode(value))).collect();
+        pairs.join("&")
+    }
+}
+
+/// The response to a [`TokenRequest`](struct.TokenRequest.html).
+#[derive(Deserialize)]
+pub struct AccessTokenResponse {
+    /// The token used to make requests on behalf of the user.
+    pub access_token: String,
+    /// The type of token, always `Bearer`.
+    pub token_type: String,
+    /// The number of seconds until the access token expires.
+    pub expires_in: u64,
+    /// The token used to get a new access token when this one expires.
+    pub refresh_token: Option<String>,
+    /// The scopes the user authorized.
+    #[serde(deserialize_with = "deserialize_scopes")]
+    pub scope: Vec<Scope>,
+    /// The webhook that was created, if the `webhook.incoming` scope was authorized.
+    pub webhook: Option<Webhook>,
+}
+
+/// Discord separates the scopes with spaces.
+fn deserialize_scopes<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<Scope>, D::Error> {
+    let scopes = String::deserialize(d)?;
+    Ok(scopes.split_whitespace().map(Scope::from).collect())
+}
+
+/// Information about the current bot's application.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/topics/oauth2#get-current-application-information)
+#[derive(Deserialize)]
+pub struct CurrentApplication {
+    /// The id of the application.
+    pub id: ApplicationId,
+    /// The name of the application.
+    pub name: String,
+    /// Icon hash.
+    pub icon: Option<String>,
+    /// The description of the application.
+    pub description: String,
+    /// URLs of the application's RPC origins, if RPC is enabled.
+    pub rpc_origins: Option<Vec<String>>,
+    /// Whether anyone can add the bot to a guild, otherwise only the owner can.
+    pub bot_public: bool,
+    /// Whether the bot needs the full OAuth2 code grant flow to be added to a guild.
+    pub bot_require_code_grant: bool,
+    /// The owner of the application, if it isn't owned by a team.
+    pub owner: Option<User>,
+    /// The hex encoded key used to verify interactions.
+    pub verify_key: String,
+    /// The team that owns the application.
+    pub team: Option<Team>,
+    /// The guild the application is sold in, if it is a game.
+    pub guild_id: Option<GuildId>,
+}
+
+impl CurrentApplication {
+    /// The users that own the application,
+    /// which are the members of its team that accepted their invite or the owner if there is no team.
+    pub fn owner_ids(&self) -> Vec<UserId> {
+        match (&self.team, &self.owner) {
+            (Some(team), _) => team.members.iter()
+                .filter(|member| member.membership_state == MembershipState::Accepted)
+                .map(|member| member.user.id)
+                .collect(),
+            (None, Some(owner)) => vec![owner.id],
+            (None, None) => Vec::new(),
+        }
+    }
+}
+
+/// A group of developers that owns applications.
+#[derive(Deserialize)]
+pub struct Team {
+    /// The id of the team.
+    pub id: TeamId,
+    /// Icon hash.
+    pub icon: Option<String>,
+    /// The members of the team.
+    pub members: Vec<TeamMember>,
+    /// The id of the user that owns the team.
+    pub owner_user_id: UserId,
+}
+
+/// A developer in a team.
+#[derive(Deserialize)]
+pub struct TeamMember {
+    /// Whether the user has accepted the invite to the team.
+    pub membership_state: MembershipState,
+    /// The permissions of the member, always `["*"]`.
+    pub permissions: Vec<String>,
+    /// The id of the team.
+    pub team_id: TeamId,
+    /// The user.
+    pub user: User,
+}
+
+/// Whether a user has accepted the invite to a team.
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, PartialEq)]
+#[repr(u8)]
+pub enum MembershipState {
+    /// The user has been invited.
+    Invited = 1,
+    /// The user has accepted the invite.
+    Accepted = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bot_invite() {
+        let url = AuthorizeUrl::new(ApplicationId::from(1))
+            .scope(Scope::Bot)
+            .scope(Scope::ApplicationsCommands)
+            .scope(Scope::Bot)
+            .permissions(Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS)
+            .guild_id(GuildId::from(2))
+            .build();
+        assert_eq!(url, Ok(format!(
+            "{}?client_id=1&scope=bot%20applications.commands&permissions={}&guild_id=2",
+            AUTHORIZE_URL,
+            (Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS).bits(),
+        )));
+    }
+
+    #[test]
+    fn code_grant() {
+        let url = AuthorizeUrl::new(ApplicationId::from(1))
+            .scope(Scope::Identify)
+            .scope(Scope::from("guilds.join"))
+            .redirect_uri("https://example.com/callback?from=discord")
+            .state("a b&c")
+            .prompt(Prompt::None)
+            .build();
+        assert_eq!(url, Ok(format!(
+            "{}?client_id=1&response_type=code&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback%3Ffrom%3Ddiscord&scope=identify%20guilds.join&state=a%20b%26c&prompt=none",
+            AUTHORIZE_URL,
+        )));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(AuthorizeUrl::new(ApplicationId::from(1)).build(), Err(AuthorizeUrlError::NoScopes));
+        let url = AuthorizeUrl::new(ApplicationId::from(1)).scope(Scope::Bot).scope(Scope::Email).build();
+        assert_eq!(url, Err(AuthorizeUrlError::MissingRedirectUri));
+    }
+
+    #[test]
+    fn token_form() {
+        let request = TokenRequest::authorization_code(ApplicationId::from(1), "secret", "co de", "https://example.com/");
+        assert_eq!(
+            request.to_form(),
+            "client_id=1&client_secret=secret&grant_type=authorization_code&code=co%20de&redirect_uri=https%3A%2F%2Fexample.com%2F",
+        );
+        let request = TokenRequest::refresh(ApplicationId::from(1), "secret", "token");
+        assert_eq!(request.to_form(), "client_id=1&client_secret=secret&grant_type=refresh_token&refresh_token=token");
+    }
+}
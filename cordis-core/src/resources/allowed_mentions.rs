@@ -0,0 +1,129 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Serialize};
+
+use super::{RoleId, UserId};
+
+/// The most roles or users that can be listed as allowed.
+const LIST_LIMIT: usize = 100;
+
+/// Which mentions in a message are allowed to notify anyone.
+///
+/// The default allows none of them, which is the safest choice for content that isn't trusted.
+/// Discord rejects listing roles or users when all mentions of that type are already allowed by `parse`.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/channel#allowed-mentions-object)
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AllowedMentions {
+    /// The types of mentions that are all allowed.
+    pub parse: Vec<MentionType>,
+    /// The roles that can be mentioned, if role mentions aren't all allowed.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub roles: Vec<RoleId>,
+    /// The users that can be mentioned, if user mentions aren't all allowed.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub users: Vec<UserId>,
+}
+
+impl AllowedMentions {
+    /// Allows every mention of the type.
+    pub fn parse(mut self, mention_type: MentionType) -> Self {
+        if !self.parse.contains(&mention_type) {
+            self.parse.push(mention_type);
+        }
+        self
+    }
+
+    /// Allows the role to be mentioned.
+    pub fn role(mut self, role: RoleId) -> Self {
+        self.roles.push(role);
+        self
+    }
+
+    /// Allows the user to be mentioned.
+    pub fn user(mut self, user: UserId) -> Self {
+        self.users.push(user);
+        self
+    }
+
+    /// Checks that Discord will accept the allowed mentions.
+    pub fn validate(&self) -> Result<(), AllowedMentionsError> {
+        if !self.roles.is_empty() && self.parse.contains(&MentionType::Roles) {
+            return Err(AllowedMentionsError::RolesAlreadyParsed);
+        }
+        if !self.users.is_empty() && self.parse.contains(&MentionType::Users) {
+            return Err(AllowedMentionsError::UsersAlreadyParsed);
+        }
+        if self.roles.len() > LIST_LIMIT {
+            return Err(AllowedMentionsError::TooManyRoles(self.roles.len()));
+        }
+        if self.users.len() > LIST_LIMIT {
+            return Err(AllowedMentionsError::TooManyUsers(self.users.len()));
+        }
+        Ok(())
+    }
+}
+
+/// Why Discord would not accept the allowed mentions.
+#[derive(Debug, PartialEq)]
+pub enum AllowedMentionsError {
+    /// Roles were listed while `parse` already allows every role mention.
+    RolesAlreadyParsed,
+    /// Users were listed while `parse` already allows every user mention.
+    UsersAlreadyParsed,
+    /// More than 100 roles were listed.
+    TooManyRoles(usize),
+    /// More than 100 users were listed.
+    TooManyUsers(usize),
+}
+
+impl Display for AllowedMentionsError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            AllowedMentionsError::RolesAlreadyParsed => write!(f, "roles can't be listed when `parse` contains `roles`"),
+            AllowedMentionsError::UsersAlreadyParsed => write!(f, "users can't be listed when `parse` contains `users`"),
+            AllowedMentionsError::TooManyRoles(count) => write!(f, "{} roles are listed but the limit is {}", count, LIST_LIMIT),
+            AllowedMentionsError::TooManyUsers(count) => write!(f, "{} users are listed but the limit is {}", count, LIST_LIMIT),
+        }
+    }
+}
+
+impl Error for AllowedMentionsError {}
+
+/// A type of mention that can be allowed.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MentionType {
+    /// Role mentions.
+    Roles,
+    /// User mentions.
+    Users,
+    /// `@everyone` and `@here`.
+    Everyone,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use super::*;
+
+    #[test]
+    fn parse_and_lists_are_exclusive() {
+        let mentions = AllowedMentions::default().parse(MentionType::Users).role(RoleId::from(1));
+        assert_eq!(mentions.validate(), Ok(()));
+        assert_eq!(to_value(&mentions).unwrap(), json!({ "parse": ["users"], "roles": ["1"] }));
+        assert_eq!(mentions.clone().user(UserId::from(2)).validate(), Err(AllowedMentionsError::UsersAlreadyParsed));
+        assert_eq!(mentions.parse(MentionType::Roles).validate(), Err(AllowedMentionsError::RolesAlreadyParsed));
+    }
+
+    #[test]
+    fn list_limits() {
+        let users = |count| (0..count).fold(AllowedMentions::default(), |mentions, id| mentions.user(UserId::from(id)));
+        assert_eq!(users(100).validate(), Ok(()));
+        assert_eq!(users(101).validate(), Err(AllowedMentionsError::TooManyUsers(101)));
+        let roles = (0..101).fold(AllowedMentions::default(), |mentions, id| mentions.role(RoleId::from(id)));
+        assert_eq!(roles.validate(), Err(AllowedMentionsError::TooManyRoles(101)));
+    }
+}
@@ -0,0 +1,19 @@
+snowflake_id! {
+    /// The id of an application, which is also the id of its bot user.
+    ApplicationId
+}
+
+snowflake_id! {
+    /// The id of a team of developers that owns applications.
+    TeamId
+}
+
+snowflake_id! {
+    /// The id of an application command.
+    CommandId
+}
+
+snowflake_id! {
+    /// The id of an interaction.
+    InteractionId
+}
@@ -1,8 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-/// An id of an attachment.
-#[derive(Deserialize)]
-pub struct AttachmentId(pub String);
+snowflake_id! {
+    /// An id of an attachment.
+    AttachmentId
+}
 
 /// A file attached to a message.
 #[derive(Deserialize)]
@@ -23,7 +24,7 @@ pub struct Attachment {
 }
 
 /// The dimensions of an image.
-#[derive(Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Dimensions {
     /// Height of the file (if image).
     pub height: u16,
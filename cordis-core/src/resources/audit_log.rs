@@ -0,0 +1,296 @@
+use serde::{de::Error as DeError, Deserialize, Deserializer};
+use serde_json::{from_value, Value};
+
+use super::{ChannelId, MessageId, PermissionOverwrite, Permissions, RoleId, Snowflake, User, UserId, Webhook};
+
+snowflake_id! {
+    /// The id of an audit log entry.
+    AuditLogEntryId
+}
+
+/// The audit log of a guild, which records the administrative actions taken in it.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/audit-log#audit-log-object)
+#[derive(Deserialize)]
+pub struct AuditLog {
+    /// The webhooks found in the entries.
+    #[serde(default)]
+    pub webhooks: Vec<Webhook>,
+    /// The users found in the entries.
+    pub users: Vec<User>,
+    /// The entries, newest first.
+    pub audit_log_entries: Vec<AuditLogEntry>,
+}
+
+/// An administrative action taken in a guild.
+#[derive(Deserialize)]
+pub struct AuditLogEntry {
+    /// The id of the entry.
+    pub id: AuditLogEntryId,
+    /// The id of the user, channel, role or other object that was affected.
+    pub target_id: Option<Snowflake>,
+    /// The changes made to the target.
+    #[serde(default)]
+    pub changes: Vec<AuditLogChange>,
+    /// The user who made the changes.
+    pub user_id: Option<UserId>,
+    /// The type of action that was taken.
+    pub action_type: AuditLogEvent,
+    /// Additional information for certain action types.
+    pub options: Option<AuditEntryInfo>,
+    /// The reason given for the change.
+    pub reason: Option<String>,
+}
+
+/// The type of action an audit log entry records.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq)]
+#[serde(from = "u16")]
+pub enum AuditLogEvent {
+    GuildUpdate,
+    ChannelCreate,
+    ChannelUpdate,
+    ChannelDelete,
+    ChannelOverwriteCreate,
+    ChannelOverwriteUpdate,
+    ChannelOverwriteDelete,
+    MemberKick,
+    MemberPrune,
+    MemberBanAdd,
+    MemberBanRemove,
+    MemberUpdate,
+    MemberRoleUpdate,
+    MemberMove,
+    MemberDisconnect,
+    BotAdd,
+    RoleCreate,
+    RoleUpdate,
+    RoleDelete,
+    InviteCreate,
+    InviteUpdate,
+    InviteDelete,
+    WebhookCreate,
+    WebhookUpdate,
+    WebhookDelete,
+    EmojiCreate,
+    EmojiUpdate,
+    EmojiDelete,
+    MessageDelete,
+    MessageBulkDelete,
+    MessagePin,
+    MessageUnpin,
+    IntegrationCreate,
+    IntegrationUpdate,
+    IntegrationDelete,
+    /// An action type that isn't known yet.
+    Unknown(u16),
+}
+
+impl From<u16> for AuditLogEvent {
+    fn from(code: u16) -> Self {
+        match code {
+            1 => AuditLogEvent::GuildUpdate,
+            10 => AuditLogEvent::ChannelCreate,
+            11 => AuditLogEvent::ChannelUpdate,
+            12 => AuditLogEvent::ChannelDelete,
+            13 => AuditLogEvent::ChannelOverwriteCreate,
+            14 => AuditLogEvent::ChannelOverwriteUpdate,
+            15 => AuditLogEvent::ChannelOverwriteDelete,
+            20 => AuditLogEvent::MemberKick,
+            21 => AuditLogEvent::MemberPrune,
+            22 => AuditLogEvent::MemberBanAdd,
+            23 => AuditLogEvent::MemberBanRemove,
+            24 => AuditLogEvent::MemberUpdate,
+            25 => AuditLogEvent::MemberRoleUpdate,
+            26 => AuditLogEvent::MemberMove,
+            27 => AuditLogEvent::MemberDisconnect,
+            28 => AuditLogEvent::BotAdd,
+            30 => AuditLogEvent::RoleCreate,
+            31 => AuditLogEvent::RoleUpdate,
+            32 => AuditLogEvent::RoleDelete,
+            40 => AuditLogEvent::InviteCreate,
+            41 => AuditLogEvent::InviteUpdate,
+            42 => AuditLogEvent::InviteDelete,
+            50 => AuditLogEvent::WebhookCreate,
+            51 => AuditLogEvent::WebhookUpdate,
+            52 => AuditLogEvent::WebhookDelete,
+            60 => AuditLogEvent::EmojiCreate,
+            61 => AuditLogEvent::EmojiUpdate,
+            62 => AuditLogEvent::EmojiDelete,
+            72 => AuditLogEvent::MessageDelete,
+            73 => AuditLogEvent::MessageBulkDelete,
+            74 => AuditLogEvent::MessagePin,
+            75 => AuditLogEvent::MessageUnpin,
+            80 => AuditLogEvent::IntegrationCreate,
+            81 => AuditLogEvent::IntegrationUpdate,
+            82 => AuditLogEvent::IntegrationDelete,
+            code => AuditLogEvent::Unknown(code),
+        }
+    }
+}
+
+/// Additional information about an audit log entry, which fields are set depends on the action type.
+///
+/// Counts are sent by Discord as strings.
+#[derive(Deserialize)]
+pub struct AuditEntryInfo {
+    /// The number of days after which inactive members were kicked, for `MemberPrune`.
+    pub delete_member_days: Option<String>,
+    /// The number of members removed by the prune, for `MemberPrune`.
+    pub members_removed: Option<String>,
+    /// The channel the entities were targeted in,
+    /// for `MemberMove`, `MessagePin`, `MessageUnpin` and `MessageDelete`.
+    pub channel_id: Option<ChannelId>,
+    /// The id of the message that was targeted, for `MessagePin` and `MessageUnpin`.
+    pub message_id: Option<MessageId>,
+    /// The number of entities that were targeted,
+    /// for `MessageDelete`, `MessageBulkDelete`, `MemberDisconnect` and `MemberMove`.
+    pub count: Option<String>,
+    /// The id of the overwritten entity, for the channel overwrite actions.
+    pub id: Option<Snowflake>,
+    /// The type of overwritten entity, `role` or `member`, for the channel overwrite actions.
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    /// The name of the role if the type is `role`, for the channel overwrite actions.
+    pub role_name: Option<String>,
+}
+
+/// The value of something before and after it was changed.
+///
+/// `old` is `None` if it was just set and `new` is `None` if it was removed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Change<T> {
+    /// The value before the change.
+    pub old: Option<T>,
+    /// The value after the change.
+    pub new: Option<T>,
+}
+
+/// A role that was added to or removed from a member.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct PartialRole {
+    /// The id of the role.
+    pub id: RoleId,
+    /// The name of the role.
+    pub name: String,
+}
+
+/// Something that was changed by an administrative action, typed by the key of the change.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/audit-log#audit-log-change-object-audit-log-change-key)
+#[derive(Clone, Debug, PartialEq)]
+pub enum AuditLogChange {
+    /// The name of a guild, channel, role, webhook or emoji changed.
+    Name(Change<String>),
+    /// A member's nickname changed.
+    Nick(Change<String>),
+    /// The permissions of a role changed.
+    Permissions(Change<Permissions>),
+    /// The permissions allowed by a channel overwrite changed.
+    Allow(Change<Permissions>),
+    /// The permissions denied by a channel overwrite changed.
+    Deny(Change<Permissions>),
+    /// The permission overwrites of a channel changed.
+    PermissionOverwrites(Change<Vec<PermissionOverwrite>>),
+    /// Roles were added to a member.
+    RoleAdd(Vec<PartialRole>),
+    /// Roles were removed from a member.
+    RoleRemove(Vec<PartialRole>),
+    /// Any other change, with its values left as JSON.
+    Other {
+        /// The key of the change.
+        key: String,
+        /// The value before the change.
+        old: Option<Value>,
+        /// The value after the change.
+        new: Option<Value>,
+    },
+}
+
+#[derive(Deserialize)]
+struct InitialAuditLogChange {
+    key: String,
+    new_value: Option<Value>,
+    old_value: Option<Value>,
+}
+
+impl<'de> Deserialize<'de> for AuditLogChange {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let InitialAuditLogChange { key, new_value, old_value } = InitialAuditLogChange::deserialize(d)?;
+        let change = match key.as_str() {
+            "name" => AuditLogChange::Name(change(old_value, new_value)?),
+            "nick" => AuditLogChange::Nick(change(old_value, new_value)?),
+            "permissions" => AuditLogChange::Permissions(change(old_value, new_value)?),
+            "allow" => AuditLogChange::Allow(change(old_value, new_value)?),
+            "deny" => AuditLogChange::Deny(change(old_value, new_value)?),
+            "permission_overwrites" => AuditLogChange::PermissionOverwrites(change(old_value, new_value)?),
+            "$add" => AuditLogChange::RoleAdd(value(new_value)?.unwrap_or_default()),
+            "$remove" => AuditLogChange::RoleRemove(value(new_value)?.unwrap_or_default()),
+            _ => AuditLogChange::Other { key, old: old_value, new: new_value },
+        };
+        Ok(change)
+    }
+}
+
+fn value<T: for<'de> Deserialize<'de>, E: DeError>(value: Option<Value>) -> Result<Option<T>, E> {
+    value.map(from_value).transpose().map_err(E::custom)
+}
+
+fn change<T: for<'de> Deserialize<'de>, E: DeError>(old: Option<Value>, new: Option<Value>) -> Result<Change<T>, E> {
+    Ok(Change { old: value(old)?, new: value(new)? })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn typed_changes() {
+        let log: AuditLog = from_value(json!({
+            "webhooks": [],
+            "users": [{ "id": "1", "username": "moderator", "discriminator": "0001" }],
+            "audit_log_entries": [
+                {
+                    "id": "10", "target_id": "20", "user_id": "1", "action_type": 31, "reason": "cleanup",
+                    "changes": [
+                        { "key": "name", "old_value": "mods", "new_value": "moderators" },
+                        { "key": "permissions", "old_value": "0", "new_value": Permissions::KICK_MEMBERS.bits().to_string() },
+                        { "key": "color", "old_value": 0, "new_value": 16711680 },
+                        { "key": "hoist", "new_value": true },
+                    ],
+                },
+                {
+                    "id": "11", "target_id": "2", "user_id": "1", "action_type": 25,
+                    "changes": [
+                        { "key": "$add", "new_value": [{ "id": "20", "name": "moderators" }] },
+                        { "key": "nick", "old_value": "old" },
+                    ],
+                },
+                {
+                    "id": "12", "target_id": "3", "user_id": "1", "action_type": 14,
+                    "options": { "id": "20", "type": "role", "role_name": "moderators" },
+                    "changes": [{ "key": "deny", "old_value": 0, "new_value": Permissions::SEND_MESSAGES.bits() }],
+                },
+                { "id": "13", "target_id": null, "user_id": "1", "action_type": 255 },
+            ],
+        })).unwrap();
+        let entries = &log.audit_log_entries;
+        assert_eq!(entries[0].action_type, AuditLogEvent::RoleUpdate);
+        assert_eq!(entries[0].changes, vec![
+            AuditLogChange::Name(Change { old: Some("mods".to_string()), new: Some("moderators".to_string()) }),
+            AuditLogChange::Permissions(Change { old: Some(Permissions::empty()), new: Some(Permissions::KICK_MEMBERS) }),
+            AuditLogChange::Other { key: "color".to_string(), old: Some(json!(0)), new: Some(json!(16711680)) },
+            AuditLogChange::Other { key: "hoist".to_string(), old: None, new: Some(json!(true)) },
+        ]);
+        assert_eq!(entries[1].changes, vec![
+            AuditLogChange::RoleAdd(vec![PartialRole { id: RoleId::from(20), name: "moderators".to_string() }]),
+            AuditLogChange::Nick(Change { old: Some("old".to_string()), new: None }),
+        ]);
+        assert_eq!(entries[2].changes, vec![AuditLogChange::Deny(Change { old: Some(Permissions::empty()), new: Some(Permissions::SEND_MESSAGES) })]);
+        assert_eq!(entries[2].options.as_ref().and_then(|options| options.role_name.as_deref()), Some("moderators"));
+        assert_eq!(entries[3].action_type, AuditLogEvent::Unknown(255));
+        assert!(entries[3].changes.is_empty());
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+use super::User;
+
+/// A user that was banned from a guild.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/guild#ban-object)
+#[derive(Deserialize)]
+pub struct Ban {
+    /// The reason given for the ban.
+    pub reason: Option<String>,
+    /// The user that was banned.
+    pub user: User,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+    use super::super::UserId;
+
+    #[test]
+    fn bans() {
+        let bans: Vec<Ban> = from_value(json!([
+            { "reason": "mentioning b1nzy", "user": { "id": "53908099506183680", "username": "Mason", "discriminator": "9999", "avatar": null } },
+            { "reason": null, "user": { "id": "80351110224678912", "username": "Nelly", "discriminator": "1337", "avatar": null } },
+        ])).unwrap();
+        assert_eq!(bans[0].reason.as_deref(), Some("mentioning b1nzy"));
+        assert_eq!(bans[0].user.id, UserId::from(53908099506183680));
+        assert_eq!(bans[1].reason, None);
+    }
+}
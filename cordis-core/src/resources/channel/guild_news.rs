@@ -1,5 +1,5 @@
 use chrono::{DateTime, Utc};
-use super::super::{ChannelId, GuildId, MessageId};
+use super::super::{ChannelId, GuildId, MessageId, PermissionOverwrite};
 
 /// A channel that users can follow and crosspost into their own server,
 pub struct GuildNewsChannel {
@@ -17,6 +17,8 @@ pub struct GuildNewsChannel {
     ///
     /// (0-1024 characters)
     pub topic: String,
+    /// Explicit permission overwrites for members and roles.
+    pub permission_overwrites: Vec<PermissionOverwrite>,
     /// Whether the channel is nsfw or not.
     pub nsfw: bool,
     /// The id of the last message sent in this channel.
@@ -24,7 +26,7 @@ pub struct GuildNewsChannel {
     /// May not point to an existing or valid message)
     pub last_message_id: Option<MessageId>,
     /// Id of the parent catergory for a channel.
-    pub parent_id: Option<MessageId>,
+    pub parent_id: Option<ChannelId>,
     /// When the last pinned message was pinned.
     pub last_pin_timestamp: Option<DateTime<Utc>>,
 }
\ No newline at end of file
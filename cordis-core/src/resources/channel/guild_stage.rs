@@ -0,0 +1,27 @@
+use super::{ChannelId, GuildId, PermissionOverwrite};
+
+/// A voice channel for hosting events with an audience.
+pub struct GuildStageChannel {
+    /// The id of this channel.
+    pub id: ChannelId,
+    /// The id of the guild.
+    pub guild_id: GuildId,
+    /// Sorting position of the channel.
+    pub position: u32,
+    /// The name of the channel.
+    ///
+    /// (1-100 characters)
+    pub name: String,
+    /// The topic of the channel, the live stage instance has its own topic.
+    pub topic: Option<String>,
+    /// Explicit permission overwrites for members and roles.
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+    /// Whether the channel is nsfw or not.
+    pub nsfw: bool,
+    /// Id of the parent catergory for a channel.
+    pub parent_id: Option<ChannelId>,
+    /// The bitrate (in bits) of the channel.
+    pub bitrate: u32,
+    /// The user limit of the channel.
+    pub user_limit: u32,
+}
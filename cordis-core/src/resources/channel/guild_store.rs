@@ -1,4 +1,4 @@
-use super::super::{ChannelId, GuildId, MessageId};
+use super::super::{ChannelId, GuildId, PermissionOverwrite};
 
 /// A channel in which game developers can sell their game on Discord.
 pub struct GuildStoreChannel {
@@ -12,8 +12,10 @@ pub struct GuildStoreChannel {
     ///
     /// (2-100 characters)
     pub name: String,
+    /// Explicit permission overwrites for members and roles.
+    pub permission_overwrites: Vec<PermissionOverwrite>,
     /// Whether the channel is nsfw or not.
     pub nsfw: bool,
     /// Id of the parent catergory for a channel.
-    pub parent_id: Option<MessageId>,
+    pub parent_id: Option<ChannelId>,
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 
-use super::{ChannelId, super::{GuildId, MessageId}};
+use super::{ChannelId, super::{GuildId, MessageId, PermissionOverwrite}};
 
 /// A text channel within a server.
 pub struct GuildTextChannel {
@@ -18,6 +18,8 @@ pub struct GuildTextChannel {
     ///
     /// (0-1024 characters)
     pub topic: String,
+    /// Explicit permission overwrites for members and roles.
+    pub permission_overwrites: Vec<PermissionOverwrite>,
     /// Whether the channel is nsfw or not.
     pub nsfw: bool,
     /// The id of the last message sent in this channel.
@@ -31,7 +33,7 @@ pub struct GuildTextChannel {
     /// Bots and Users with the permission ``manage_messages`` or ``manage_channel`` are unafected.
     pub rate_limit_per_user: u16,
     /// Id of the parent catergory for a channel.
-    pub parent_id: Option<MessageId>,
+    pub parent_id: Option<ChannelId>,
     /// When the last pinned message was pinned.
     pub last_pin_timestamp: Option<DateTime<Utc>>,
 }
@@ -1,4 +1,4 @@
-use super::super::{ChannelId, GuildId, MessageId};
+use super::super::{ChannelId, GuildId, PermissionOverwrite};
 
 /// A voice channel within a server.
 pub struct GuildVoiceChannel {
@@ -12,10 +12,12 @@ pub struct GuildVoiceChannel {
     ///
     /// (2-100 characters)
     pub name: String,
+    /// Explicit permission overwrites for members and roles.
+    pub permission_overwrites: Vec<PermissionOverwrite>,
     /// Whether the channel is nsfw or not.
     pub nsfw: bool,
     /// Id of the parent catergory for a channel.
-    pub parent_id: Option<MessageId>,
+    pub parent_id: Option<ChannelId>,
     /// The bitrate (in bits) of the voice channel.
     pub bitrate: u32,
     /// The user limit of the voice channel.
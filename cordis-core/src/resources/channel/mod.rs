@@ -1,10 +1,9 @@
 //! Contains the various Channel types.
 
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Deserializer};
-use serde_repr::{Deserialize_repr};
+use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize};
 
-use super::{GuildId, MessageId, UserId};
+use super::{GuildId, MessageId, PermissionOverwrite, UserId};
 
 mod dm;
 pub use dm::DMChannel;
@@ -14,16 +13,27 @@ mod group_dm;
 pub use group_dm::GroupDMChannel;
 mod guild_news;
 pub use guild_news::GuildNewsChannel;
+mod guild_stage;
+pub use guild_stage::GuildStageChannel;
 mod guild_store;
 pub use guild_store::GuildStoreChannel;
 mod guild_text;
 pub use guild_text::GuildTextChannel;
 mod guild_voice;
 pub use guild_voice::GuildVoiceChannel;
+mod thread;
+pub use thread::{ThreadChannel, ThreadMember, ThreadMetadata};
+mod traits;
+pub use traits::{ChannelCommon, GuildChannel, TextChannel};
+mod tree;
+pub use tree::{CategoryNode, channel_tree, ChannelTree};
+mod unknown;
+pub use unknown::UnknownChannel;
 
-/// A snowflake which is the id of a channel.
-#[derive(Deserialize)]
-pub struct ChannelId(String);
+snowflake_id! {
+    /// A snowflake which is the id of a channel.
+    ChannelId
+}
 
 /// Represents a Discord guild or DM channel.
 pub enum Channel {
@@ -41,26 +51,87 @@ pub enum Channel {
     GuildNews(GuildNewsChannel),
     /// A channel in which game developers can sell their game on Discord.
     GuildStore(GuildStoreChannel),
+    /// A news, public or private thread within a text or news channel.
+    Thread(ThreadChannel),
+    /// A voice channel for hosting events with an audience.
+    GuildStage(GuildStageChannel),
+    /// A type of channel that isn't supported yet.
+    Unknown(UnknownChannel),
 }
 
 /// The type of channel it is.
-#[derive(Deserialize_repr)]
-#[repr(u8)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
 pub enum ChannelType {
     /// A text channel within a server.
-    GuildText = 0,
+    GuildText,
     /// A direct message between users.
-    Dm = 1,
+    Dm,
     /// A voice channel within a server.
-    GuildVoice = 2,
+    GuildVoice,
     /// A direct message between multiple users.
-    GroupDm = 3,
+    GroupDm,
     /// An organisational category that contains channels.
-    GuildCategory = 4,
+    GuildCategory,
     /// A channel that users can follow and crosspost into their own server,
-    GuildNews = 5,
+    GuildNews,
     /// A channel in which game developers can sell their game on Discord.
-    GuildStore = 6,
+    GuildStore,
+    /// A thread within a news channel.
+    GuildNewsThread,
+    /// A thread within a text channel that anyone in the channel can see.
+    GuildPublicThread,
+    /// A thread within a text channel that only those invited and moderators can see.
+    GuildPrivateThread,
+    /// A voice channel for hosting events with an audience.
+    GuildStageVoice,
+    /// A type of channel that isn't supported yet.
+    Unknown(u8),
+}
+
+impl ChannelType {
+    /// Whether this is a news, public or private thread.
+    pub fn is_thread(self) -> bool {
+        matches!(self, ChannelType::GuildNewsThread | ChannelType::GuildPublicThread | ChannelType::GuildPrivateThread)
+    }
+}
+
+impl From<u8> for ChannelType {
+    fn from(code: u8) -> Self {
+        match code {
+            0 => ChannelType::GuildText,
+            1 => ChannelType::Dm,
+            2 => ChannelType::GuildVoice,
+            3 => ChannelType::GroupDm,
+            4 => ChannelType::GuildCategory,
+            5 => ChannelType::GuildNews,
+            6 => ChannelType::GuildStore,
+            10 => ChannelType::GuildNewsThread,
+            11 => ChannelType::GuildPublicThread,
+            12 => ChannelType::GuildPrivateThread,
+            13 => ChannelType::GuildStageVoice,
+            code => ChannelType::Unknown(code),
+        }
+    }
+}
+
+impl From<ChannelType> for u8 {
+    fn from(kind: ChannelType) -> Self {
+        match kind {
+            ChannelType::GuildText => 0,
+            ChannelType::Dm => 1,
+            ChannelType::GuildVoice => 2,
+            ChannelType::GroupDm => 3,
+            ChannelType::GuildCategory => 4,
+            ChannelType::GuildNews => 5,
+            ChannelType::GuildStore => 6,
+            ChannelType::GuildNewsThread => 10,
+            ChannelType::GuildPublicThread => 11,
+            ChannelType::GuildPrivateThread => 12,
+            ChannelType::GuildStageVoice => 13,
+            ChannelType::Unknown(code) => code,
+        }
+    }
 }
 
 #[derive(Deserialize)]
@@ -73,26 +144,47 @@ struct InitialChannel {
     name: Option<String>,
     topic: Option<String>,
     nsfw: Option<bool>,
+    #[serde(default)]
+    permission_overwrites: Vec<PermissionOverwrite>,
     last_message_id: Option<MessageId>,
     bitrate: Option<u32>,
     user_limit: Option<u32>,
     rate_limit_per_user: Option<u16>,
     icon: Option<String>,
     owner_id: Option<UserId>,
-    parent_id: Option<MessageId>,
+    parent_id: Option<ChannelId>,
     last_pin_timestamp: Option<DateTime<Utc>>,
+    message_count: Option<u32>,
+    member_count: Option<u32>,
+    thread_metadata: Option<ThreadMetadata>,
+    member: Option<ThreadMember>,
+}
+
+impl Channel {
+    /// The explicit permission overwrites of the channel, which is empty for DMs and threads.
+    pub fn permission_overwrites(&self) -> &[PermissionOverwrite] {
+        match self {
+            Channel::GuildText(channel) => &channel.permission_overwrites,
+            Channel::GuildVoice(channel) => &channel.permission_overwrites,
+            Channel::GuildCategory(channel) => &channel.permission_overwrites,
+            Channel::GuildNews(channel) => &channel.permission_overwrites,
+            Channel::GuildStore(channel) => &channel.permission_overwrites,
+            Channel::GuildStage(channel) => &channel.permission_overwrites,
+            Channel::DM(_) | Channel::GroupDm(_) | Channel::Thread(_) | Channel::Unknown(_) => &[],
+        }
+    }
 }
 
 impl<'de> Deserialize<'de> for Channel {
     fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
         let InitialChannel{
             id, code,guild_id, position, name, topic,
-            nsfw, last_message_id, rate_limit_per_user, parent_id, last_pin_timestamp,
-            bitrate, user_limit, icon, owner_id,
+            nsfw, permission_overwrites, last_message_id, rate_limit_per_user, parent_id, last_pin_timestamp,
+            bitrate, user_limit, icon, owner_id, message_count, member_count, thread_metadata, member,
         } = InitialChannel::deserialize(d)?;
         Ok(match code {
             ChannelType::GuildText => Channel::GuildText(GuildTextChannel{
-                id, last_message_id, parent_id, last_pin_timestamp,
+                id, permission_overwrites, last_message_id, parent_id, last_pin_timestamp,
                 guild_id: guild_id.expect("Could not find `guild_id` for GuiltTextChannel."),
                 position: position.expect("Could not find `position` for GuildTextChannel."),
                 name: name.expect("Could not find `name` for GuildTextChannel."),
@@ -104,7 +196,7 @@ impl<'de> Deserialize<'de> for Channel {
                 id, last_message_id, last_pin_timestamp,
             }),
             ChannelType::GuildVoice => Channel::GuildVoice(GuildVoiceChannel{
-                id, parent_id,
+                id, permission_overwrites, parent_id,
                 guild_id: guild_id.expect("Could not find `guild_id` for GuildVoiceChannel."),
                 position: position.expect("Could not find `position` for GUildVoiceChannel."),
                 name: name.expect("Could not find `name` for GuildVoiceChannel."),
@@ -119,14 +211,14 @@ impl<'de> Deserialize<'de> for Channel {
                 owner_id: owner_id.expect("Could not find `owner_id` for GroupDMChannel."),
             }),
             ChannelType::GuildCategory => Channel::GuildCategory(GuildCategoryChannel{
-                id, parent_id,
+                id, permission_overwrites, parent_id,
                 name: name.expect("Could not find `name` for GuildCategoryChannel."),
                 guild_id: guild_id.expect("Could not find `guild_id` for GuildCategoryChannel."),
                 position: position.expect("Could not find `position` for GuildCategoryChannel."),
                 nsfw: nsfw.expect("Could not find `nsfw` for GuildCategoryChannel."),
             }),
             ChannelType::GuildNews => Channel::GuildNews(GuildNewsChannel{
-                id, parent_id, last_message_id, last_pin_timestamp,
+                id, permission_overwrites, parent_id, last_message_id, last_pin_timestamp,
                 name: name.expect("Could not find `name` for GuildNewsChannel."),
                 topic: topic.expect("Could not find `topic` for GuildNewsChannel."),
                 guild_id: guild_id.expect("Could not find `guild_id` for GuildNewsChannel."),
@@ -134,12 +226,29 @@ impl<'de> Deserialize<'de> for Channel {
                 nsfw: nsfw.expect("Could not find `nsfw` for GuildNewsChannel."),
             }),
             ChannelType::GuildStore => Channel::GuildStore(GuildStoreChannel{
-                id, parent_id,
+                id, permission_overwrites, parent_id,
                 name: name.expect("Could not find `name` for GuildStoreChannel."),
                 guild_id: guild_id.expect("Could not find `guild_id` for GuildStoreChannel."),
                 nsfw: nsfw.expect("Could not find `nsfw` for GuildStoreChannel."),
                 position: position.expect("Could not find `position` for GuildStoreChannel."),
             }),
+            ChannelType::GuildNewsThread | ChannelType::GuildPublicThread | ChannelType::GuildPrivateThread => Channel::Thread(ThreadChannel{
+                id, parent_id, owner_id, last_message_id, last_pin_timestamp, rate_limit_per_user, message_count, member_count, member,
+                kind: code,
+                guild_id: guild_id.ok_or_else(|| D::Error::missing_field("guild_id"))?,
+                name: name.ok_or_else(|| D::Error::missing_field("name"))?,
+                thread_metadata: thread_metadata.ok_or_else(|| D::Error::missing_field("thread_metadata"))?,
+            }),
+            ChannelType::GuildStageVoice => Channel::GuildStage(GuildStageChannel{
+                id, permission_overwrites, parent_id, topic,
+                guild_id: guild_id.ok_or_else(|| D::Error::missing_field("guild_id"))?,
+                position: position.ok_or_else(|| D::Error::missing_field("position"))?,
+                name: name.ok_or_else(|| D::Error::missing_field("name"))?,
+                nsfw: nsfw.unwrap_or(false),
+                bitrate: bitrate.ok_or_else(|| D::Error::missing_field("bitrate"))?,
+                user_limit: user_limit.unwrap_or(0),
+            }),
+            ChannelType::Unknown(code) => Channel::Unknown(UnknownChannel{ id, code, guild_id, name }),
         })
     }
 }
\ No newline at end of file
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::{ChannelId, ChannelType, super::{GuildId, MessageId, UserId}};
+
+/// A thread within a text or news channel.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/topics/threads)
+pub struct ThreadChannel {
+    /// The id of this thread.
+    pub id: ChannelId,
+    /// Whether it is a news, public or private thread.
+    pub kind: ChannelType,
+    /// The id of the guild.
+    pub guild_id: GuildId,
+    /// The id of the channel the thread was created in.
+    pub parent_id: Option<ChannelId>,
+    /// The id of the user that created the thread.
+    pub owner_id: Option<UserId>,
+    /// The name of the thread.
+    ///
+    /// (1-100 characters)
+    pub name: String,
+    /// The id of the last message sent in this thread.
+    pub last_message_id: Option<MessageId>,
+    /// When the last pinned message was pinned.
+    pub last_pin_timestamp: Option<DateTime<Utc>>,
+    /// Amount of seconds a user has to wait before sending another message.
+    pub rate_limit_per_user: Option<u16>,
+    /// An approximate count of the messages in the thread, which stops counting at 50.
+    pub message_count: Option<u32>,
+    /// An approximate count of the users in the thread, which stops counting at 50.
+    pub member_count: Option<u32>,
+    /// Whether the thread is archived or locked.
+    pub thread_metadata: ThreadMetadata,
+    /// The current user, if they have joined the thread.
+    pub member: Option<ThreadMember>,
+}
+
+/// Whether a thread is archived or locked.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/channel#thread-metadata-object)
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ThreadMetadata {
+    /// Whether the thread is archived.
+    pub archived: bool,
+    /// Minutes of no activity after which the thread is archived, one of 60, 1440, 4320 or 10080.
+    pub auto_archive_duration: u32,
+    /// When the thread was last archived or unarchived.
+    pub archive_timestamp: DateTime<Utc>,
+    /// Whether only users with `MANAGE_THREADS` can unarchive the thread.
+    pub locked: bool,
+    /// Whether users that aren't moderators can add others to a private thread.
+    pub invitable: Option<bool>,
+    /// When the thread was created, only sent for threads created after 2022-01-09.
+    pub create_timestamp: Option<DateTime<Utc>>,
+}
+
+/// A user that has joined a thread.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/channel#thread-member-object)
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ThreadMember {
+    /// The id of the thread, not sent when the member is part of a thread.
+    pub id: Option<ChannelId>,
+    /// The id of the user, not sent when the member is part of a thread.
+    pub user_id: Option<UserId>,
+    /// When the user last joined the thread.
+    pub join_timestamp: DateTime<Utc>,
+    /// Notification settings of the user.
+    pub flags: u32,
+}
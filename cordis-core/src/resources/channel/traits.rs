@@ -0,0 +1,179 @@
+use chrono::{DateTime, Utc};
+
+use super::{
+    Channel, ChannelId, ChannelType, DMChannel, GroupDMChannel, GuildCategoryChannel, GuildNewsChannel,
+    GuildStageChannel, GuildStoreChannel, GuildTextChannel, GuildVoiceChannel, ThreadChannel, UnknownChannel,
+    super::{GuildId, MessageId},
+};
+
+/// Information that every channel has.
+pub trait ChannelCommon {
+    /// The id of the channel.
+    fn id(&self) -> ChannelId;
+    /// The type of channel it is.
+    fn kind(&self) -> ChannelType;
+}
+
+/// Information that every channel within a guild has.
+pub trait GuildChannel: ChannelCommon {
+    /// The id of the guild.
+    fn guild_id(&self) -> GuildId;
+    /// The name of the channel.
+    fn name(&self) -> &str;
+    /// Sorting position of the channel.
+    fn position(&self) -> u32;
+    /// The id of the category the channel is in.
+    fn parent_id(&self) -> Option<ChannelId>;
+    /// Whether the channel is nsfw or not.
+    fn nsfw(&self) -> bool;
+}
+
+/// Information that every channel messages can be sent in has.
+pub trait TextChannel: ChannelCommon {
+    /// The id of the last message sent in this channel.
+    fn last_message_id(&self) -> Option<MessageId>;
+    /// When the last pinned message was pinned.
+    fn last_pin_timestamp(&self) -> Option<DateTime<Utc>>;
+}
+
+macro_rules! channel_common {
+    ($($channel:ty => $kind:ident),* $(,)?) => {$(
+        impl ChannelCommon for $channel {
+            fn id(&self) -> ChannelId {
+                self.id
+            }
+
+            fn kind(&self) -> ChannelType {
+                ChannelType::$kind
+            }
+        }
+    )*};
+}
+
+macro_rules! guild_channel {
+    ($($channel:ty),* $(,)?) => {$(
+        impl GuildChannel for $channel {
+            fn guild_id(&self) -> GuildId {
+                self.guild_id
+            }
+
+            fn name(&self) -> &str {
+                &self.name
+            }
+
+            fn position(&self) -> u32 {
+                self.position
+            }
+
+            fn parent_id(&self) -> Option<ChannelId> {
+                self.parent_id
+            }
+
+            fn nsfw(&self) -> bool {
+                self.nsfw
+            }
+        }
+    )*};
+}
+
+macro_rules! text_channel {
+    ($($channel:ty),* $(,)?) => {$(
+        impl TextChannel for $channel {
+            fn last_message_id(&self) -> Option<MessageId> {
+                self.last_message_id
+            }
+
+            fn last_pin_timestamp(&self) -> Option<DateTime<Utc>> {
+                self.last_pin_timestamp
+            }
+        }
+    )*};
+}
+
+channel_common! {
+    GuildTextChannel => GuildText,
+    DMChannel => Dm,
+    GuildVoiceChannel => GuildVoice,
+    GroupDMChannel => GroupDm,
+    GuildCategoryChannel => GuildCategory,
+    GuildNewsChannel => GuildNews,
+    GuildStoreChannel => GuildStore,
+    GuildStageChannel => GuildStageVoice,
+}
+
+impl ChannelCommon for ThreadChannel {
+    fn id(&self) -> ChannelId {
+        self.id
+    }
+
+    fn kind(&self) -> ChannelType {
+        self.kind
+    }
+}
+
+impl ChannelCommon for UnknownChannel {
+    fn id(&self) -> ChannelId {
+        self.id
+    }
+
+    fn kind(&self) -> ChannelType {
+        ChannelType::Unknown(self.code)
+    }
+}
+
+guild_channel!(GuildTextChannel, GuildVoiceChannel, GuildCategoryChannel, GuildNewsChannel, GuildStoreChannel, GuildStageChannel);
+
+text_channel!(GuildTextChannel, DMChannel, GroupDMChannel, GuildNewsChannel, ThreadChannel);
+
+impl Channel {
+    /// The channel as one that is listed in a guild, `None` for DMs, threads and unknown channels.
+    pub fn as_guild_channel(&self) -> Option<&dyn GuildChannel> {
+        match self {
+            Channel::GuildText(channel) => Some(channel),
+            Channel::GuildVoice(channel) => Some(channel),
+            Channel::GuildCategory(channel) => Some(channel),
+            Channel::GuildNews(channel) => Some(channel),
+            Channel::GuildStore(channel) => Some(channel),
+            Channel::GuildStage(channel) => Some(channel),
+            Channel::DM(_) | Channel::GroupDm(_) | Channel::Thread(_) | Channel::Unknown(_) => None,
+        }
+    }
+
+    /// The channel as one that messages can be sent in, `None` for voice channels, categories and store channels.
+    pub fn as_text_channel(&self) -> Option<&dyn TextChannel> {
+        match self {
+            Channel::GuildText(channel) => Some(channel),
+            Channel::DM(channel) => Some(channel),
+            Channel::GroupDm(channel) => Some(channel),
+            Channel::GuildNews(channel) => Some(channel),
+            Channel::Thread(channel) => Some(channel),
+            Channel::GuildVoice(_) | Channel::GuildCategory(_) | Channel::GuildStore(_) | Channel::GuildStage(_)
+            | Channel::Unknown(_) => None,
+        }
+    }
+
+    fn as_common(&self) -> &dyn ChannelCommon {
+        match self {
+            Channel::GuildText(channel) => channel,
+            Channel::DM(channel) => channel,
+            Channel::GuildVoice(channel) => channel,
+            Channel::GroupDm(channel) => channel,
+            Channel::GuildCategory(channel) => channel,
+            Channel::GuildNews(channel) => channel,
+            Channel::GuildStore(channel) => channel,
+            Channel::Thread(channel) => channel,
+            Channel::GuildStage(channel) => channel,
+            Channel::Unknown(channel) => channel,
+        }
+    }
+}
+
+impl ChannelCommon for Channel {
+    fn id(&self) -> ChannelId {
+        self.as_common().id()
+    }
+
+    fn kind(&self) -> ChannelType {
+        self.as_common().kind()
+    }
+}
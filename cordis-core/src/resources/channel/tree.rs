@@ -0,0 +1,109 @@
+use super::{Channel, ChannelCommon, ChannelType, GuildCategoryChannel};
+
+/// The channels of a guild laid out like Discord's sidebar.
+pub struct ChannelTree<'a> {
+    /// Channels that aren't in a category, which are shown above all the categories.
+    pub uncategorized: Vec<&'a Channel>,
+    /// The categories sorted by position.
+    pub categories: Vec<CategoryNode<'a>>,
+}
+
+/// A category in the sidebar along with its channels.
+pub struct CategoryNode<'a> {
+    /// The category.
+    pub category: &'a GuildCategoryChannel,
+    /// The channels in the category, in the order they are shown.
+    pub channels: Vec<&'a Channel>,
+}
+
+/// Arranges the channels of a guild into the tree shown in Discord's sidebar.
+///
+/// Categories are sorted by position. Within a category, and for channels without one,
+/// text channels come before voice and stage channels and each are sorted by position.
+/// Discord breaks ties in position with the channel id.
+/// Channels whose category isn't in the list are treated as uncategorized, and DMs and threads are ignored.
+pub fn channel_tree(channels: &[Channel]) -> ChannelTree<'_> {
+    let mut categories: Vec<CategoryNode> = channels.iter()
+        .filter_map(|channel| match channel {
+            Channel::GuildCategory(category) => Some(CategoryNode { category, channels: Vec::new() }),
+            _ => None,
+        })
+        .collect();
+    categories.sort_by_key(|node| (node.category.position, node.category.id));
+    let mut uncategorized = Vec::new();
+    for channel in channels {
+        let guild_channel = match channel.as_guild_channel() {
+            Some(guild_channel) if guild_channel.kind() != ChannelType::GuildCategory => guild_channel,
+            _ => continue,
+        };
+        let parent = categories.iter_mut()
+            .find(|node| Some(node.category.id) == guild_channel.parent_id());
+        match parent {
+            Some(node) => node.channels.push(channel),
+            None => uncategorized.push(channel),
+        }
+    }
+    sort(&mut uncategorized);
+    for node in &mut categories {
+        sort(&mut node.channels);
+    }
+    ChannelTree { uncategorized, categories }
+}
+
+fn sort(channels: &mut [&Channel]) {
+    channels.sort_by_key(|channel| {
+        let voice = matches!(channel.kind(), ChannelType::GuildVoice | ChannelType::GuildStageVoice);
+        let position = channel.as_guild_channel().map_or(0, |channel| channel.position());
+        (voice, position, channel.id())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json, Value};
+
+    use super::*;
+    use super::super::ChannelId;
+
+    fn guild_channel(id: u64, kind: u8, position: u32, parent_id: Option<u64>) -> Value {
+        json!({
+            "id": id.to_string(), "type": kind, "guild_id": "1", "position": position, "name": "channel",
+            "topic": "", "nsfw": false, "rate_limit_per_user": 0, "bitrate": 64000, "user_limit": 0,
+            "parent_id": parent_id.map(|id| id.to_string()),
+        })
+    }
+
+    fn ids(channels: &[&Channel]) -> Vec<ChannelId> {
+        channels.iter().map(|channel| channel.id()).collect()
+    }
+
+    #[test]
+    fn sidebar_order() {
+        let channels: Vec<Channel> = from_value(json!([
+            guild_channel(10, 4, 1, None),
+            guild_channel(12, 4, 0, None),
+            guild_channel(11, 4, 0, None),
+            guild_channel(20, 2, 0, Some(11)),
+            guild_channel(22, 0, 5, Some(11)),
+            guild_channel(21, 0, 5, Some(11)),
+            guild_channel(23, 0, 1, Some(11)),
+            guild_channel(24, 13, 0, Some(11)),
+            guild_channel(25, 5, 0, Some(10)),
+            guild_channel(30, 0, 2, Some(99)),
+            guild_channel(31, 2, 0, None),
+            guild_channel(32, 0, 3, None),
+            { "id": "40", "type": 1, "last_message_id": null },
+            {
+                "id": "41", "type": 11, "guild_id": "1", "name": "thread", "parent_id": "21",
+                "thread_metadata": { "archived": false, "auto_archive_duration": 60, "archive_timestamp": "2021-01-01T00:00:00Z", "locked": false },
+            },
+        ])).unwrap();
+        let tree = channel_tree(&channels);
+        let categories: Vec<ChannelId> = tree.categories.iter().map(|node| node.category.id).collect();
+        assert_eq!(categories, vec![ChannelId::from(11), ChannelId::from(12), ChannelId::from(10)]);
+        assert_eq!(ids(&tree.categories[0].channels), [23, 21, 22, 20, 24].map(ChannelId::from));
+        assert!(tree.categories[1].channels.is_empty());
+        assert_eq!(ids(&tree.categories[2].channels), vec![ChannelId::from(25)]);
+        assert_eq!(ids(&tree.uncategorized), [30, 32, 31].map(ChannelId::from));
+    }
+}
@@ -0,0 +1,13 @@
+use super::{ChannelId, super::GuildId};
+
+/// A type of channel that isn't supported yet, which only has the fields every channel has.
+pub struct UnknownChannel {
+    /// The id of this channel.
+    pub id: ChannelId,
+    /// The type code sent by Discord.
+    pub code: u8,
+    /// The id of the guild, if it is in one.
+    pub guild_id: Option<GuildId>,
+    /// The name of the channel, if it has one.
+    pub name: Option<String>,
+}
@@ -0,0 +1,907 @@
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{de::Error as DeError, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use super::ReactionType;
+
+/// The most action rows a message or modal can have.
+pub const MAX_ACTION_ROWS: usize = 5;
+/// The most buttons an action row can have.
+pub const MAX_BUTTONS_PER_ROW: usize = 5;
+/// The most characters a `custom_id` can have.
+pub const MAX_CUSTOM_ID_LENGTH: usize = 100;
+/// The most characters the title of a modal can have.
+pub const MAX_MODAL_TITLE_LENGTH: usize = 45;
+/// The most options a select menu can have, which is also the most that can be chosen.
+pub const MAX_SELECT_OPTIONS: usize = 25;
+
+/// A component at the top level of a message or modal.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TopLevelComponent {
+    /// A row of interactive components.
+    ActionRow(ActionRow),
+    /// A type of component that isn't supported yet, kept as Discord sent it so it can be sent back unchanged.
+    Unknown(Value),
+}
+
+impl TopLevelComponent {
+    /// The type of the component.
+    pub fn kind(&self) -> ComponentType {
+        match self {
+            TopLevelComponent::ActionRow(_) => ComponentType::ActionRow,
+            TopLevelComponent::Unknown(value) => kind_of(value),
+        }
+    }
+
+    /// The row, if the component is an action row.
+    pub fn as_action_row(&self) -> Option<&ActionRow> {
+        match self {
+            TopLevelComponent::ActionRow(row) => Some(row),
+            TopLevelComponent::Unknown(_) => None,
+        }
+    }
+}
+
+impl From<ActionRow> for TopLevelComponent {
+    fn from(row: ActionRow) -> Self {
+        TopLevelComponent::ActionRow(row)
+    }
+}
+
+/// A row of interactive components attached to a message or shown in a modal.
+///
+/// A row holds up to 5 buttons, or a single select menu or text input.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/interactions/message-components#action-rows)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ActionRow {
+    /// The components in the row.
+    pub components: Vec<Component>,
+}
+
+impl ActionRow {
+    /// An empty row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a component to the row.
+    pub fn component(mut self, component: impl Into<Component>) -> Self {
+        self.components.push(component.into());
+        self
+    }
+}
+
+/// An interactive component in an action row.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Component {
+    /// A clickable button.
+    Button(Button),
+    /// A dropdown of options.
+    SelectMenu(SelectMenu),
+    /// A text field, only used in modals.
+    TextInput(TextInput),
+    /// A type of component that isn't supported yet, or a button with a style that isn't,
+    /// kept as Discord sent it so it can be sent back unchanged.
+    Other(Value),
+}
+
+impl Component {
+    /// The type of the component.
+    pub fn kind(&self) -> ComponentType {
+        match self {
+            Component::Button(_) => ComponentType::Button,
+            Component::SelectMenu(_) => ComponentType::SelectMenu,
+            Component::TextInput(_) => ComponentType::TextInput,
+            Component::Other(value) => kind_of(value),
+        }
+    }
+
+    /// The id the application gave the component, `None` for link buttons.
+    pub fn custom_id(&self) -> Option<&str> {
+        match self {
+            Component::Button(button) => button.custom_id.as_deref(),
+            Component::SelectMenu(menu) => Some(&menu.custom_id),
+            Component::TextInput(input) => Some(&input.custom_id),
+            Component::Other(value) => value.get("custom_id").and_then(Value::as_str),
+        }
+    }
+}
+
+/// The type of a component sent by Discord, `Other(0)` if it has none.
+fn kind_of(value: &Value) -> ComponentType {
+    value.get("type")
+        .and_then(Value::as_u64)
+        .and_then(|code| u8::try_from(code).ok())
+        .map_or(ComponentType::Other(0), ComponentType::from)
+}
+
+impl From<Button> for Component {
+    fn from(button: Button) -> Self {
+        Component::Button(button)
+    }
+}
+
+impl From<SelectMenu> for Component {
+    fn from(menu: SelectMenu) -> Self {
+        Component::SelectMenu(menu)
+    }
+}
+
+impl From<TextInput> for Component {
+    fn from(input: TextInput) -> Self {
+        Component::TextInput(input)
+    }
+}
+
+/// The type of a component.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum ComponentType {
+    ActionRow,
+    Button,
+    SelectMenu,
+    TextInput,
+    /// A type of component that isn't supported yet.
+    Other(u8),
+}
+
+impl From<u8> for ComponentType {
+    fn from(code: u8) -> Self {
+        match code {
+            1 => ComponentType::ActionRow,
+            2 => ComponentType::Button,
+            3 => ComponentType::SelectMenu,
+            4 => ComponentType::TextInput,
+            code => ComponentType::Other(code),
+        }
+    }
+}
+
+impl From<ComponentType> for u8 {
+    fn from(kind: ComponentType) -> Self {
+        match kind {
+            ComponentType::ActionRow => 1,
+            ComponentType::Button => 2,
+            ComponentType::SelectMenu => 3,
+            ComponentType::TextInput => 4,
+            ComponentType::Other(code) => code,
+        }
+    }
+}
+
+/// A clickable button, which either sends an interaction or opens a URL.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Button {
+    /// How the button looks.
+    pub style: ButtonStyle,
+    /// The text on the button.
+    pub label: Option<String>,
+    /// The emoji on the button.
+    pub emoji: Option<ReactionType>,
+    /// The id sent with the interaction, `None` for link buttons.
+    pub custom_id: Option<String>,
+    /// The URL opened by a link button.
+    pub url: Option<String>,
+    /// Whether the button can't be clicked.
+    pub disabled: bool,
+}
+
+impl Button {
+    /// A button that sends an interaction with the `custom_id` when clicked.
+    pub fn new(style: ButtonStyle, custom_id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            style,
+            label: Some(label.into()),
+            emoji: None,
+            custom_id: Some(custom_id.into()),
+            url: None,
+            disabled: false,
+        }
+    }
+
+    /// A button that opens the URL when clicked.
+    pub fn link(url: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            style: ButtonStyle::Link,
+            label: Some(label.into()),
+            emoji: None,
+            custom_id: None,
+            url: Some(url.into()),
+            disabled: false,
+        }
+    }
+
+    /// Sets the emoji on the button.
+    pub fn emoji(mut self, emoji: ReactionType) -> Self {
+        self.emoji = Some(emoji);
+        self
+    }
+
+    /// Sets whether the button can't be clicked.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// How a button looks.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/interactions/message-components#button-object-button-styles)
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum ButtonStyle {
+    /// Blurple.
+    Primary,
+    /// Grey.
+    Secondary,
+    /// Green.
+    Success,
+    /// Red.
+    Danger,
+    /// Grey, and opens a URL.
+    Link,
+    /// A style that isn't supported yet.
+    Unknown(u8),
+}
+
+impl From<u8> for ButtonStyle {
+    fn from(code: u8) -> Self {
+        match code {
+            1 => ButtonStyle::Primary,
+            2 => ButtonStyle::Secondary,
+            3 => ButtonStyle::Success,
+            4 => ButtonStyle::Danger,
+            5 => ButtonStyle::Link,
+            code => ButtonStyle::Unknown(code),
+        }
+    }
+}
+
+impl From<ButtonStyle> for u8 {
+    fn from(style: ButtonStyle) -> Self {
+        match style {
+            ButtonStyle::Primary => 1,
+            ButtonStyle::Secondary => 2,
+            ButtonStyle::Success => 3,
+            ButtonStyle::Danger => 4,
+            ButtonStyle::Link => 5,
+            ButtonStyle::Unknown(code) => code,
+        }
+    }
+}
+
+/// A dropdown of options to choose from.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectMenu {
+    /// The id sent with the interaction.
+    pub custom_id: String,
+    /// The options to choose from, up to 25.
+    pub options: Vec<SelectOption>,
+    /// The text shown when nothing is chosen.
+    pub placeholder: Option<String>,
+    /// The least options that have to be chosen, 1 if not set.
+    pub min_values: Option<u8>,
+    /// The most options that can be chosen, 1 if not set.
+    pub max_values: Option<u8>,
+    /// Whether the menu can't be used.
+    pub disabled: bool,
+}
+
+impl SelectMenu {
+    /// A menu with no options.
+    pub fn new(custom_id: impl Into<String>) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            options: Vec::new(),
+            placeholder: None,
+            min_values: None,
+            max_values: None,
+            disabled: false,
+        }
+    }
+
+    /// Adds an option.
+    pub fn option(mut self, option: SelectOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Sets the text shown when nothing is chosen.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets how many options can be chosen.
+    pub fn values(mut self, min: u8, max: u8) -> Self {
+        self.min_values = Some(min);
+        self.max_values = Some(max);
+        self
+    }
+
+    /// Sets whether the menu can't be used.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// An option of a select menu.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SelectOption {
+    /// The text shown to the user.
+    pub label: String,
+    /// The value sent with the interaction.
+    pub value: String,
+    /// Extra text shown under the label.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The emoji shown next to the label.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ReactionType>,
+    /// Whether the option is chosen when the menu is shown.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub default: bool,
+}
+
+impl SelectOption {
+    /// An option with the label and value.
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self { label: label.into(), value: value.into(), description: None, emoji: None, default: false }
+    }
+
+    /// Sets the text shown under the label.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the emoji shown next to the label.
+    pub fn emoji(mut self, emoji: ReactionType) -> Self {
+        self.emoji = Some(emoji);
+        self
+    }
+
+    /// Sets whether the option is chosen when the menu is shown.
+    pub fn default(mut self, default: bool) -> Self {
+        self.default = default;
+        self
+    }
+}
+
+/// A text field shown in a modal.
+///
+/// When a modal is submitted, only `custom_id` and `value` are sent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextInput {
+    /// The id sent with the interaction.
+    pub custom_id: String,
+    /// Whether the field is a single line or a paragraph.
+    pub style: TextInputStyle,
+    /// The text shown above the field.
+    pub label: String,
+    /// The least characters that have to be written.
+    pub min_length: Option<u16>,
+    /// The most characters that can be written.
+    pub max_length: Option<u16>,
+    /// Whether the field has to be filled in, `true` if not set.
+    pub required: Option<bool>,
+    /// The text already in the field, or what the user wrote when the modal was submitted.
+    pub value: Option<String>,
+    /// The text shown when the field is empty.
+    pub placeholder: Option<String>,
+}
+
+impl TextInput {
+    /// A field with the label.
+    pub fn new(custom_id: impl Into<String>, style: TextInputStyle, label: impl Into<String>) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            style,
+            label: label.into(),
+            min_length: None,
+            max_length: None,
+            required: None,
+            value: None,
+            placeholder: None,
+        }
+    }
+
+    /// Sets how many characters can be written.
+    pub fn length(mut self, min: u16, max: u16) -> Self {
+        self.min_length = Some(min);
+        self.max_length = Some(max);
+        self
+    }
+
+    /// Sets whether the field has to be filled in.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    /// Sets the text already in the field.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets the text shown when the field is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+}
+
+/// Whether a text input is a single line or a paragraph.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum TextInputStyle {
+    #[default]
+    Short,
+    Paragraph,
+    /// A style that isn't supported yet.
+    Unknown(u8),
+}
+
+impl From<u8> for TextInputStyle {
+    fn from(code: u8) -> Self {
+        match code {
+            1 => TextInputStyle::Short,
+            2 => TextInputStyle::Paragraph,
+            code => TextInputStyle::Unknown(code),
+        }
+    }
+}
+
+impl From<TextInputStyle> for u8 {
+    fn from(style: TextInputStyle) -> Self {
+        match style {
+            TextInputStyle::Short => 1,
+            TextInputStyle::Paragraph => 2,
+            TextInputStyle::Unknown(code) => code,
+        }
+    }
+}
+
+/// Why Discord would not accept the components of a message or modal.
+#[derive(Debug, PartialEq)]
+pub enum ComponentError {
+    /// There are more than 5 action rows.
+    TooManyRows(usize),
+    /// An action row has no components.
+    EmptyRow {
+        /// The index of the row.
+        row: usize,
+    },
+    /// An action row has more than 5 buttons.
+    TooManyButtons {
+        /// The index of the row.
+        row: usize,
+        /// The number of buttons.
+        count: usize,
+    },
+    /// A select menu or text input shares its action row with another component.
+    NotAlone {
+        /// The index of the row.
+        row: usize,
+    },
+    /// A component can't be used there, like a text input in a message or a button in a modal.
+    NotAllowed {
+        /// The index of the row.
+        row: usize,
+        /// The type of the component.
+        kind: ComponentType,
+    },
+    /// A link button has a `custom_id` or no URL, or another button has a URL or no `custom_id`.
+    InvalidButton {
+        /// The index of the row.
+        row: usize,
+        /// The index of the button in the row.
+        index: usize,
+    },
+    /// A select menu has no options or more than 25.
+    InvalidOptionCount {
+        /// The index of the row.
+        row: usize,
+        /// The number of options.
+        count: usize,
+    },
+    /// A select menu's `min_values` is more than its `max_values`, or `max_values` is 0 or more than its number of options.
+    InvalidValueCount {
+        /// The index of the row.
+        row: usize,
+        /// The least options that have to be chosen.
+        min: u8,
+        /// The most options that can be chosen.
+        max: u8,
+    },
+    /// A `custom_id` is longer than 100 characters.
+    CustomIdTooLong(usize),
+    /// The title of a modal is empty or longer than 45 characters.
+    InvalidTitleLength(usize),
+}
+
+impl Display for ComponentError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            ComponentError::TooManyRows(count) => write!(f, "there are {} action rows but the limit is {}", count, MAX_ACTION_ROWS),
+            ComponentError::EmptyRow { row } => write!(f, "action row {} is empty", row),
+            ComponentError::TooManyButtons { row, count } => write!(f, "action row {} has {} buttons but the limit is {}", row, count, MAX_BUTTONS_PER_ROW),
+            ComponentError::NotAlone { row } => write!(f, "action row {} has a select menu or text input with other components", row),
+            ComponentError::NotAllowed { row, kind } => write!(f, "action row {} has a component of type {} which can't be used here", row, u8::from(*kind)),
+            ComponentError::InvalidButton { row, index } => write!(f, "button {} of action row {} needs either a custom_id or a URL", index, row),
+            ComponentError::InvalidOptionCount { row, count } => write!(f, "the select menu in action row {} has {} options but needs 1 to {}", row, count, MAX_SELECT_OPTIONS),
+            ComponentError::InvalidValueCount { row, min, max } => write!(f, "the select menu in action row {} can't have between {} and {} options chosen", row, min, max),
+            ComponentError::CustomIdTooLong(length) => write!(f, "a custom_id has {} characters but the limit is {}", length, MAX_CUSTOM_ID_LENGTH),
+            ComponentError::InvalidTitleLength(length) => write!(f, "the title has {} characters but needs 1 to {}", length, MAX_MODAL_TITLE_LENGTH),
+        }
+    }
+}
+
+impl Error for ComponentError {}
+
+/// Checks that Discord will accept the action rows of a message.
+pub fn validate_components(rows: &[TopLevelComponent]) -> Result<(), ComponentError> {
+    validate_rows(rows, |kind| matches!(kind, ComponentType::Button | ComponentType::SelectMenu))
+}
+
+/// Checks that Discord will accept the action rows of a modal, which can only hold text inputs.
+pub fn validate_modal_components(rows: &[TopLevelComponent]) -> Result<(), ComponentError> {
+    validate_rows(rows, |kind| kind == ComponentType::TextInput)
+}
+
+/// Checks that a `custom_id` isn't longer than 100 characters.
+pub fn validate_custom_id(custom_id: &str) -> Result<(), ComponentError> {
+    let length = custom_id.chars().count();
+    if length > MAX_CUSTOM_ID_LENGTH {
+        return Err(ComponentError::CustomIdTooLong(length));
+    }
+    Ok(())
+}
+
+fn validate_rows(rows: &[TopLevelComponent], allowed: impl Fn(ComponentType) -> bool) -> Result<(), ComponentError> {
+    if rows.len() > MAX_ACTION_ROWS {
+        return Err(ComponentError::TooManyRows(rows.len()));
+    }
+    for (row, component) in rows.iter().enumerate() {
+        let components = match component {
+            TopLevelComponent::ActionRow(action_row) => &action_row.components,
+            TopLevelComponent::Unknown(_) => return Err(ComponentError::NotAllowed { row, kind: component.kind() }),
+        };
+        if components.is_empty() {
+            return Err(ComponentError::EmptyRow { row });
+        }
+        for (index, component) in components.iter().enumerate() {
+            if !allowed(component.kind()) {
+                return Err(ComponentError::NotAllowed { row, kind: component.kind() });
+            }
+            if let Some(custom_id) = component.custom_id() {
+                validate_custom_id(custom_id)?;
+            }
+            match component {
+                Component::Button(button) => {
+                    let valid = match button.style {
+                        ButtonStyle::Link => button.url.is_some() && button.custom_id.is_none(),
+                        _ => button.custom_id.is_some() && button.url.is_none(),
+                    };
+                    if !valid {
+                        return Err(ComponentError::InvalidButton { row, index });
+                    }
+                },
+                Component::SelectMenu(menu) => {
+                    if menu.options.is_empty() || menu.options.len() > MAX_SELECT_OPTIONS {
+                        return Err(ComponentError::InvalidOptionCount { row, count: menu.options.len() });
+                    }
+                    let (min, max) = (menu.min_values.unwrap_or(1), menu.max_values.unwrap_or(1));
+                    if max == 0 || min > max || usize::from(max) > menu.options.len() {
+                        return Err(ComponentError::InvalidValueCount { row, min, max });
+                    }
+                },
+                Component::TextInput(_) | Component::Other(_) => {},
+            }
+            if components.len() > 1 && component.kind() != ComponentType::Button {
+                return Err(ComponentError::NotAlone { row });
+            }
+        }
+        if components.len() > MAX_BUTTONS_PER_ROW {
+            return Err(ComponentError::TooManyButtons { row, count: components.len() });
+        }
+    }
+    Ok(())
+}
+
+/// How all components are sent by Discord, with the fields of every type.
+#[derive(Default, Deserialize, Serialize)]
+struct InitialComponent {
+    #[serde(rename = "type")]
+    kind: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emoji: Option<ReactionType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    disabled: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    options: Vec<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    placeholder: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_values: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_values: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_length: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+}
+
+impl InitialComponent {
+    /// Unknown components and buttons with unknown styles keep the raw value, so no fields are lost.
+    fn into_component<E: DeError>(self, raw: Value) -> Result<Component, E> {
+        Ok(match ComponentType::from(self.kind) {
+            ComponentType::Button => match self.style.map(ButtonStyle::from).ok_or_else(|| E::missing_field("style"))? {
+                ButtonStyle::Unknown(_) => Component::Other(raw),
+                style => Component::Button(Button {
+                    style,
+                    label: self.label,
+                    emoji: self.emoji,
+                    custom_id: self.custom_id,
+                    url: self.url,
+                    disabled: self.disabled,
+                }),
+            },
+            ComponentType::SelectMenu => Component::SelectMenu(SelectMenu {
+                custom_id: self.custom_id.ok_or_else(|| E::missing_field("custom_id"))?,
+                options: self.options,
+                placeholder: self.placeholder,
+                min_values: self.min_values,
+                max_values: self.max_values,
+                disabled: self.disabled,
+            }),
+            ComponentType::TextInput => Component::TextInput(TextInput {
+                custom_id: self.custom_id.ok_or_else(|| E::missing_field("custom_id"))?,
+                style: self.style.map(TextInputStyle::from).unwrap_or_default(),
+                label: self.label.unwrap_or_default(),
+                min_length: self.min_length,
+                max_length: self.max_length,
+                required: self.required,
+                value: self.value,
+                placeholder: self.placeholder,
+            }),
+            _ => Component::Other(raw),
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let raw = Value::deserialize(d)?;
+        InitialComponent::deserialize(&raw).map_err(D::Error::custom)?.into_component(raw)
+    }
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let initial = match self.clone() {
+            Component::Button(button) => InitialComponent {
+                kind: ComponentType::Button.into(),
+                style: Some(button.style.into()),
+                label: button.label,
+                emoji: button.emoji,
+                custom_id: button.custom_id,
+                url: button.url,
+                disabled: button.disabled,
+                ..Default::default()
+            },
+            Component::SelectMenu(menu) => InitialComponent {
+                kind: ComponentType::SelectMenu.into(),
+                custom_id: Some(menu.custom_id),
+                options: menu.options,
+                placeholder: menu.placeholder,
+                min_values: menu.min_values,
+                max_values: menu.max_values,
+                disabled: menu.disabled,
+                ..Default::default()
+            },
+            Component::TextInput(input) => InitialComponent {
+                kind: ComponentType::TextInput.into(),
+                custom_id: Some(input.custom_id),
+                style: Some(input.style.into()),
+                label: Some(input.label),
+                min_length: input.min_length,
+                max_length: input.max_length,
+                required: input.required,
+                value: input.value,
+                placeholder: input.placeholder,
+                ..Default::default()
+            },
+            Component::Other(raw) => return raw.serialize(s),
+        };
+        initial.serialize(s)
+    }
+}
+
+/// How an action row is sent by Discord.
+#[derive(Deserialize)]
+struct InitialActionRow {
+    #[serde(rename = "type")]
+    kind: u8,
+    #[serde(default)]
+    components: Vec<Component>,
+}
+
+impl<'de> Deserialize<'de> for ActionRow {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let initial = InitialActionRow::deserialize(d)?;
+        if ComponentType::from(initial.kind) != ComponentType::ActionRow {
+            return Err(D::Error::custom("expected an action row"));
+        }
+        Ok(ActionRow { components: initial.components })
+    }
+}
+
+impl Serialize for ActionRow {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut row = s.serialize_struct("ActionRow", 2)?;
+        row.serialize_field("type", &u8::from(ComponentType::ActionRow))?;
+        row.serialize_field("components", &self.components)?;
+        row.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TopLevelComponent {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let raw = Value::deserialize(d)?;
+        if kind_of(&raw) != ComponentType::ActionRow {
+            return Ok(TopLevelComponent::Unknown(raw));
+        }
+        ActionRow::deserialize(raw).map(TopLevelComponent::ActionRow).map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for TopLevelComponent {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            TopLevelComponent::ActionRow(row) => row.serialize(s),
+            TopLevelComponent::Unknown(raw) => raw.serialize(s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json, to_value};
+
+    use super::*;
+
+    fn button(id: &str) -> Button {
+        Button::new(ButtonStyle::Primary, id, "Click")
+    }
+
+    #[test]
+    fn round_trip() {
+        let rows = vec![
+            ActionRow::new()
+                .component(button("yes").emoji(ReactionType::Unicode("👍".to_string())))
+                .component(Button::link("https://discord.com", "Open")),
+            ActionRow::new().component(SelectMenu::new("colour").option(SelectOption::new("Red", "red").default(true))),
+            ActionRow::new().component(TextInput::new("name", TextInputStyle::Paragraph, "Name").length(1, 20)),
+        ];
+        let value = to_value(&rows).unwrap();
+        assert_eq!(value, json!([
+            {"type": 1, "components": [
+                {"type": 2, "style": 1, "label": "Click", "emoji": {"id": null, "name": "👍"}, "custom_id": "yes"},
+                {"type": 2, "style": 5, "label": "Open", "url": "https://discord.com"}
+            ]},
+            {"type": 1, "components": [
+                {"type": 3, "custom_id": "colour", "options": [{"label": "Red", "value": "red", "default": true}]}
+            ]},
+            {"type": 1, "components": [
+                {"type": 4, "custom_id": "name", "style": 2, "label": "Name", "min_length": 1, "max_length": 20}
+            ]}
+        ]));
+        assert_eq!(from_value::<Vec<ActionRow>>(value).unwrap(), rows);
+    }
+
+    #[test]
+    fn unknown_components() {
+        let payload = json!([
+            {"type": 1, "components": [{"type": 5, "custom_id": "users", "min_values": 1, "default_values": []}]},
+            {"type": 17, "accent_color": 703487, "components": [{"type": 10, "content": "Hi"}]}
+        ]);
+        let components: Vec<TopLevelComponent> = from_value(payload.clone()).unwrap();
+        match &components[..] {
+            [TopLevelComponent::ActionRow(row), TopLevelComponent::Unknown(_)] => {
+                assert_eq!(row.components[0].kind(), ComponentType::Other(5));
+                assert_eq!(row.components[0].custom_id(), Some("users"));
+            },
+            components => panic!("expected a row and an unknown component but got {:?}", components),
+        }
+        assert_eq!(components[1].kind(), ComponentType::Other(17));
+        assert_eq!(to_value(&components).unwrap(), payload);
+        assert!(from_value::<ActionRow>(json!({"type": 17, "components": []})).is_err());
+    }
+
+    #[test]
+    fn unknown_styles() {
+        let payload = json!({"type": 1, "components": [{"type": 2, "style": 6, "sku_id": "1"}]});
+        let row: ActionRow = from_value(payload.clone()).unwrap();
+        assert_eq!(row.components[0].kind(), ComponentType::Button);
+        assert!(matches!(row.components[0], Component::Other(_)));
+        assert_eq!(to_value(&row).unwrap(), payload);
+        assert_eq!(ButtonStyle::from(6), ButtonStyle::Unknown(6));
+        assert_eq!(u8::from(ButtonStyle::Link), 5);
+
+        let payload = json!({"type": 1, "components": [{"type": 4, "custom_id": "name", "style": 3, "label": "Name"}]});
+        let row: ActionRow = from_value(payload.clone()).unwrap();
+        match &row.components[..] {
+            [Component::TextInput(input)] => assert_eq!(input.style, TextInputStyle::Unknown(3)),
+            components => panic!("expected a text input but got {:?}", components),
+        }
+        assert_eq!(to_value(&row).unwrap(), payload);
+    }
+
+    #[test]
+    fn limits() {
+        let row = |count| TopLevelComponent::from((0..count).fold(ActionRow::new(), |row, i| row.component(button(&i.to_string()))));
+        assert_eq!(validate_components(&vec![row(5); 5]), Ok(()));
+        assert_eq!(validate_components(&vec![row(1); 6]), Err(ComponentError::TooManyRows(6)));
+        assert_eq!(validate_components(&[row(6)]), Err(ComponentError::TooManyButtons { row: 0, count: 6 }));
+        let long = ActionRow::new().component(button(&"a".repeat(101)));
+        assert_eq!(validate_components(&[long.into()]), Err(ComponentError::CustomIdTooLong(101)));
+        assert_eq!(validate_custom_id(&"a".repeat(100)), Ok(()));
+    }
+
+    #[test]
+    fn select_menu_values() {
+        let menu = |options, min, max| {
+            let menu = (0..options).fold(SelectMenu::new("menu"), |menu, i: usize| menu.option(SelectOption::new("Option", i.to_string())));
+            [ActionRow::new().component(menu.values(min, max)).into()]
+        };
+        assert_eq!(validate_components(&menu(25, 0, 25)), Ok(()));
+        assert_eq!(validate_components(&menu(3, 3, 3)), Ok(()));
+        let default = ActionRow::new().component(SelectMenu::new("menu").option(SelectOption::new("A", "a")));
+        assert_eq!(validate_components(&[default.into()]), Ok(()));
+        assert_eq!(validate_components(&menu(25, 0, 26)), Err(ComponentError::InvalidValueCount { row: 0, min: 0, max: 26 }));
+        assert_eq!(validate_components(&menu(3, 0, 4)), Err(ComponentError::InvalidValueCount { row: 0, min: 0, max: 4 }));
+        assert_eq!(validate_components(&menu(3, 2, 1)), Err(ComponentError::InvalidValueCount { row: 0, min: 2, max: 1 }));
+        assert_eq!(validate_components(&menu(3, 0, 0)), Err(ComponentError::InvalidValueCount { row: 0, min: 0, max: 0 }));
+        assert_eq!(validate_components(&menu(26, 1, 1)), Err(ComponentError::InvalidOptionCount { row: 0, count: 26 }));
+    }
+
+    #[test]
+    fn invalid_rows() {
+        let menu = SelectMenu::new("menu").option(SelectOption::new("A", "a"));
+        let mixed = ActionRow::new().component(button("a")).component(menu.clone());
+        assert_eq!(validate_components(&[mixed.into()]), Err(ComponentError::NotAlone { row: 0 }));
+        assert_eq!(validate_components(&[ActionRow::new().into()]), Err(ComponentError::EmptyRow { row: 0 }));
+        let link = Button { custom_id: Some("a".to_string()), ..Button::link("https://discord.com", "Open") };
+        assert_eq!(validate_components(&[ActionRow::new().component(link).into()]), Err(ComponentError::InvalidButton { row: 0, index: 0 }));
+        let input = [ActionRow::new().component(TextInput::new("name", TextInputStyle::Short, "Name")).into()];
+        assert_eq!(validate_components(&input), Err(ComponentError::NotAllowed { row: 0, kind: ComponentType::TextInput }));
+        assert_eq!(validate_modal_components(&input), Ok(()));
+        assert_eq!(
+            validate_modal_components(&[ActionRow::new().component(menu).into()]),
+            Err(ComponentError::NotAllowed { row: 0, kind: ComponentType::SelectMenu }),
+        );
+        let unknown = [TopLevelComponent::Unknown(json!({"type": 17, "components": []}))];
+        assert_eq!(validate_components(&unknown), Err(ComponentError::NotAllowed { row: 0, kind: ComponentType::Other(17) }));
+    }
+}
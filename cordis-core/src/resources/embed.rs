@@ -1,104 +1,372 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::Dimensions;
 
+const TITLE_LIMIT: usize = 256;
+const DESCRIPTION_LIMIT: usize = 2048;
+const FIELD_COUNT_LIMIT: usize = 25;
+const FIELD_NAME_LIMIT: usize = 256;
+const FIELD_VALUE_LIMIT: usize = 1024;
+const FOOTER_LIMIT: usize = 2048;
+const AUTHOR_NAME_LIMIT: usize = 256;
+const TOTAL_LIMIT: usize = 6000;
+
 /// An embed of a message.
-#[derive(Deserialize)]
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/channel#embed-limits)
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Embed {
     /// Title of the embed.
-    pub embed: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// Type of embed.
     ///
     /// Always `rich` for webhook embeds.
-    #[serde(rename = "type")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub type_: Option<String>,
     /// Description of the embed.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// URL of the embed.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
     /// Timestamp of embedded conten.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<DateTime<Utc>>,
     /// Colour code of the embed.
-    pub color: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<u32>,
     /// Footer information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub footer: Option<EmbedFooter>,
     /// Image information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image: Option<EmbedImage>,
     /// Thumbnail information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<EmbedImage>,
     /// Video information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub video: Option<EmbedVideo>,
     /// Provider information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub provider: Option<EmbedProvider>,
     /// Author information.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<EmbedAuthor>,
     /// Fields information.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<EmbedField>,
 }
 
+impl Embed {
+    /// Starts building an embed to send.
+    pub fn builder() -> EmbedBuilder {
+        EmbedBuilder(Self::default())
+    }
+
+    /// The number of characters that count towards the total limit of an embed.
+    ///
+    /// This is the title, description, field names and values, footer text and author name.
+    pub fn length(&self) -> usize {
+        let fields: usize = self.fields.iter().map(|field| count(&field.name) + count(&field.value)).sum();
+        self.title.as_deref().map_or(0, count)
+            + self.description.as_deref().map_or(0, count)
+            + self.footer.as_ref().map_or(0, |footer| count(&footer.text))
+            + self.author.as_ref().and_then(|author| author.name.as_deref()).map_or(0, count)
+            + fields
+    }
+
+    /// Checks that the embed is within all of Discord's limits.
+    pub fn validate(&self) -> Result<(), EmbedError> {
+        check(self.title.as_deref(), TITLE_LIMIT, EmbedError::TitleTooLong)?;
+        check(self.description.as_deref(), DESCRIPTION_LIMIT, EmbedError::DescriptionTooLong)?;
+        if self.fields.len() > FIELD_COUNT_LIMIT {
+            return Err(EmbedError::TooManyFields(self.fields.len()));
+        }
+        for (index, field) in self.fields.iter().enumerate() {
+            check(Some(&field.name), FIELD_NAME_LIMIT, |length| EmbedError::FieldNameTooLong { index, length })?;
+            check(Some(&field.value), FIELD_VALUE_LIMIT, |length| EmbedError::FieldValueTooLong { index, length })?;
+        }
+        check(self.footer.as_ref().map(|footer| footer.text.as_str()), FOOTER_LIMIT, EmbedError::FooterTooLong)?;
+        check(self.author.as_ref().and_then(|author| author.name.as_deref()), AUTHOR_NAME_LIMIT, EmbedError::AuthorNameTooLong)?;
+        let length = self.length();
+        if length > TOTAL_LIMIT {
+            return Err(EmbedError::TooLong(length));
+        }
+        Ok(())
+    }
+}
+
+/// Discord limits the number of characters, not bytes.
+fn count(text: &str) -> usize {
+    text.chars().count()
+}
+
+fn check(text: Option<&str>, limit: usize, error: impl FnOnce(usize) -> EmbedError) -> Result<(), EmbedError> {
+    match text.map(count) {
+        Some(length) if length > limit => Err(error(length)),
+        _ => Ok(()),
+    }
+}
+
+/// The limit of an embed that was broken, with the number of characters or fields it had.
+#[derive(Debug, PartialEq)]
+pub enum EmbedError {
+    /// The title was longer than 256 characters.
+    TitleTooLong(usize),
+    /// The description was longer than 2048 characters.
+    DescriptionTooLong(usize),
+    /// There were more than 25 fields.
+    TooManyFields(usize),
+    /// The name of a field was longer than 256 characters.
+    FieldNameTooLong {
+        /// The index of the field.
+        index: usize,
+        /// The number of characters in the name.
+        length: usize,
+    },
+    /// The value of a field was longer than 1024 characters.
+    FieldValueTooLong {
+        /// The index of the field.
+        index: usize,
+        /// The number of characters in the value.
+        length: usize,
+    },
+    /// The footer text was longer than 2048 characters.
+    FooterTooLong(usize),
+    /// The name of the author was longer than 256 characters.
+    AuthorNameTooLong(usize),
+    /// The embed had more than 6000 characters in total.
+    TooLong(usize),
+}
+
+impl Display for EmbedError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            EmbedError::TitleTooLong(length) => write!(f, "the title has {} characters but the limit is {}", length, TITLE_LIMIT),
+            EmbedError::DescriptionTooLong(length) => write!(f, "the description has {} characters but the limit is {}", length, DESCRIPTION_LIMIT),
+            EmbedError::TooManyFields(count) => write!(f, "there are {} fields but the limit is {}", count, FIELD_COUNT_LIMIT),
+            EmbedError::FieldNameTooLong { index, length } => write!(f, "the name of field {} has {} characters but the limit is {}", index, length, FIELD_NAME_LIMIT),
+            EmbedError::FieldValueTooLong { index, length } => write!(f, "the value of field {} has {} characters but the limit is {}", index, length, FIELD_VALUE_LIMIT),
+            EmbedError::FooterTooLong(length) => write!(f, "the footer has {} characters but the limit is {}", length, FOOTER_LIMIT),
+            EmbedError::AuthorNameTooLong(length) => write!(f, "the author name has {} characters but the limit is {}", length, AUTHOR_NAME_LIMIT),
+            EmbedError::TooLong(length) => write!(f, "the embed has {} characters in total but the limit is {}", length, TOTAL_LIMIT),
+        }
+    }
+}
+
+impl Error for EmbedError {}
+
+/// Builds an [`Embed`](struct.Embed.html) to send, checking it is within Discord's limits.
+pub struct EmbedBuilder(Embed);
+
+impl EmbedBuilder {
+    /// Sets the title.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.0.title = Some(title.into());
+        self
+    }
+
+    /// Sets the description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.0.description = Some(description.into());
+        self
+    }
+
+    /// Sets the URL that the title links to.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.0.url = Some(url.into());
+        self
+    }
+
+    /// Sets the timestamp shown in the footer.
+    pub fn timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.0.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the colour of the embed's border as an RGB value.
+    pub fn color(mut self, color: u32) -> Self {
+        self.0.color = Some(color);
+        self
+    }
+
+    /// Sets the footer text and its icon.
+    pub fn footer(mut self, text: impl Into<String>, icon_url: Option<String>) -> Self {
+        self.0.footer = Some(EmbedFooter { text: text.into(), icon_url, proxy_icon_url: None });
+        self
+    }
+
+    /// Sets the large image shown at the bottom.
+    pub fn image(mut self, url: impl Into<String>) -> Self {
+        self.0.image = Some(EmbedImage::new(url.into()));
+        self
+    }
+
+    /// Sets the small image shown in the top right.
+    pub fn thumbnail(mut self, url: impl Into<String>) -> Self {
+        self.0.thumbnail = Some(EmbedImage::new(url.into()));
+        self
+    }
+
+    /// Sets the author's name, the URL it links to and their icon.
+    pub fn author(mut self, name: impl Into<String>, url: Option<String>, icon_url: Option<String>) -> Self {
+        self.0.author = Some(EmbedAuthor { name: Some(name.into()), url, icon_url, proxy_icon_url: None });
+        self
+    }
+
+    /// Adds a field, which can be shown next to other inline fields.
+    pub fn field(mut self, name: impl Into<String>, value: impl Into<String>, inline: bool) -> Self {
+        self.0.fields.push(EmbedField { name: name.into(), value: value.into(), inline: Some(inline) });
+        self
+    }
+
+    /// Checks the embed is within Discord's limits and returns it.
+    pub fn build(self) -> Result<Embed, EmbedError> {
+        self.0.validate()?;
+        Ok(self.0)
+    }
+}
+
 /// The footer of an embed.
-#[derive(Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedFooter {
     /// Footer text.
     pub text: String,
     /// URL of footer icon (only supports http(s) and attachments).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon_url: Option<String>,
     /// A proxied URL of the footer icon.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub proxy_icon_url: Option<String>,
 }
 
 /// The image of an embed.
-#[derive(Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedImage {
     /// Source of the image (http(s) or attachment).
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
     /// Proxy url of the image.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub proxy_url: Option<String>,
     /// The dimensions of the image.
-    #[serde(flatten)]
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
     pub dimensions: Option<Dimensions>,
 }
 
+impl EmbedImage {
+    fn new(url: String) -> Self {
+        Self { url: Some(url), proxy_url: None, dimensions: None }
+    }
+}
+
 /// The video of an embed.
-#[derive(Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedVideo {
     /// The source url of the video.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
     /// The dimensions of the url.
-    #[serde(flatten)]
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
     pub dimensions: Option<Dimensions>,
 }
 
 /// The provider of an embed.
-#[derive(Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedProvider {
     /// Name of provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// Url of provider.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
 }
 
 /// The author of an embed.
-#[derive(Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedAuthor {
     /// The name of the author.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// The url of the author.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
     /// The url source of the author icon.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon_url: Option<String>,
     /// The proxy url of the author icon.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub proxy_icon_url: Option<String>,
 }
 
 /// A field of an embed.
-#[derive(Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EmbedField {
     /// Name of the field.
     pub name: String,
     /// Value of the field.
     pub value: String,
     /// Whether or not the field should be inline.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub inline: Option<bool>,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(length: usize) -> String {
+        // Multi-byte characters check that characters are counted rather than bytes.
+        "é".repeat(length)
+    }
+
+    #[test]
+    fn title_and_description() {
+        assert!(Embed::builder().title(text(256)).description(text(2048)).build().is_ok());
+        assert_eq!(Embed::builder().title(text(257)).build().err(), Some(EmbedError::TitleTooLong(257)));
+        assert_eq!(Embed::builder().description(text(2049)).build().err(), Some(EmbedError::DescriptionTooLong(2049)));
+    }
+
+    #[test]
+    fn fields() {
+        let fields = |count| (0..count).fold(Embed::builder(), |builder, _| builder.field("name", "value", false));
+        assert!(fields(25).build().is_ok());
+        assert_eq!(fields(26).build().err(), Some(EmbedError::TooManyFields(26)));
+
+        assert!(Embed::builder().field(text(256), text(1024), true).build().is_ok());
+        assert_eq!(
+            Embed::builder().field("name", "value", true).field(text(257), "value", true).build().err(),
+            Some(EmbedError::FieldNameTooLong { index: 1, length: 257 }),
+        );
+        assert_eq!(
+            Embed::builder().field("name", text(1025), true).build().err(),
+            Some(EmbedError::FieldValueTooLong { index: 0, length: 1025 }),
+        );
+    }
+
+    #[test]
+    fn footer_and_author() {
+        assert!(Embed::builder().footer(text(2048), None).author(text(256), None, None).build().is_ok());
+        assert_eq!(Embed::builder().footer(text(2049), None).build().err(), Some(EmbedError::FooterTooLong(2049)));
+        assert_eq!(Embed::builder().author(text(257), None, None).build().err(), Some(EmbedError::AuthorNameTooLong(257)));
+    }
+
+    #[test]
+    fn total_length() {
+        let embed = |extra: usize| Embed::builder()
+            .title(text(256))
+            .description(text(2048))
+            .footer(text(2048), None)
+            .author(text(256), None, None)
+            .field(text(256), text(1024), false)
+            .field(text(100), text(12 + extra), false);
+        assert_eq!(embed(0).build().map(|embed| embed.length()).ok(), Some(6000));
+        assert_eq!(embed(1).build().err(), Some(EmbedError::TooLong(6001)));
+    }
+}
@@ -1,10 +1,11 @@
 use serde::Deserialize;
 
-use super::{Role, User};
+use super::{RoleId, User};
 
-/// The id of an emoji.
-#[derive(Deserialize)]
-pub struct EmojiId(pub String);
+snowflake_id! {
+    /// The id of an emoji.
+    EmojiId
+}
 
 /// An emoji that was uploaded to a guild.
 #[derive(Deserialize)]
@@ -15,8 +16,9 @@ pub struct Emoji {
     ///
     /// Can be `None` only in Reactions.
     pub name: Option<String>,
-    /// Roles this emoji is whitelisted to.
-    pub roles: Vec<Role>,
+    /// Ids of the roles this emoji is whitelisted to.
+    #[serde(default)]
+    pub roles: Vec<RoleId>,
     /// User that created this role.
     pub user: Option<User>,
     /// Whether this emoji must be wrapped in colons.
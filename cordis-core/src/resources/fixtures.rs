@@ -0,0 +1,33 @@
+//! Guilds, roles and members shared by the permission and moderation tests.
+
+use serde_json::{from_value, json, Value};
+
+use super::{Guild, GuildMember, Permissions};
+
+/// A role with the id, position and permissions.
+pub fn role(id: u64, position: u32, permissions: Permissions) -> Value {
+    json!({
+        "id": id.to_string(), "name": "role", "color": 0, "hoist": false, "position": position,
+        "permissions": permissions.bits(), "managed": false, "mentionable": false,
+    })
+}
+
+/// A guild with the owner and roles, whose id is also the id of its `@everyone` role.
+pub fn guild(id: u64, owner: u64, roles: Vec<Value>) -> Guild {
+    from_value(json!({
+        "id": id.to_string(), "name": "guild", "icon": null, "splash": null, "owner": false,
+        "owner_id": owner.to_string(), "permissions": 0, "region": "sydney", "afk_timeout": 300,
+        "verification_level": 0, "default_message_notifications": 0, "explicit_content_filter": 0,
+        "emojis": [], "features": [], "mfa_level": 0, "premium_tier": 0, "preferred_locale": "en-US",
+        "roles": roles,
+    })).unwrap()
+}
+
+/// A member of a guild with the roles.
+pub fn member(user: u64, roles: &[u64]) -> GuildMember {
+    let roles: Vec<String> = roles.iter().map(u64::to_string).collect();
+    from_value(json!({
+        "user": { "id": user.to_string(), "username": "user", "discriminator": "0001" },
+        "roles": roles, "joined_at": "2020-01-01T00:00:00Z", "deaf": false, "mute": false,
+    })).unwrap()
+}
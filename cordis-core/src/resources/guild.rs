@@ -1,11 +1,12 @@
 use serde::Deserialize;
 use serde_repr::Deserialize_repr;
 
-use super::{Emoji, Role, ChannelId};
+use super::{Emoji, Permissions, Role, ChannelId, Sticker, UserId};
 
-/// The id of a guild.
-#[derive(Deserialize)]
-pub struct GuildId(pub String);
+snowflake_id! {
+    /// The id of a guild.
+    GuildId
+}
 
 /// A Discord Guild.
 #[derive(Deserialize)]
@@ -22,8 +23,10 @@ pub struct Guild {
     pub splash: Option<String>,
     /// Whether or not the user is the owner of the guild.
     pub owner: bool,
+    /// The id of the owner of the guild.
+    pub owner_id: UserId,
     /// Total permissions for the user in the guild (does not include channel overides).
-    pub permissions: u32,
+    pub permissions: Permissions,
     /// Voice region id for the guild.
     pub region: String,
     /// Id of the AFK channel.
@@ -35,7 +38,7 @@ pub struct Guild {
     /// The channel id that the widget will generate an invite to.
     pub embed_channel_id: Option<ChannelId>,
     /// Verification level required for the guild.
-    pub verification_level: u32,
+    pub verification_level: VerificationLevel,
     /// The default message notification level.
     pub default_message_notifications: DefaultMessageNotificationLevel,
     /// The explicit content filter level.
@@ -44,6 +47,9 @@ pub struct Guild {
     pub roles: Vec<Role>,
     /// Custom guild emojis.
     pub emojis: Vec<Emoji>,
+    /// Custom guild stickers.
+    #[serde(default)]
+    pub stickers: Vec<Sticker>,
     /// Enabled guild features.
     pub features: Vec<GuildFeatures>,
     /// Required MFA level for this guild.
@@ -85,9 +91,25 @@ pub enum DefaultMessageNotificationLevel {
     OnlyMentions = 1,
 }
 
+/// The verification a member needs before they can send messages in the guild.
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, PartialEq)]
+#[repr(u8)]
+pub enum VerificationLevel {
+    /// Unrestricted.
+    None = 0,
+    /// Must have a verified email on their account.
+    Low = 1,
+    /// Must also be registered on Discord for longer than 5 minutes.
+    Medium = 2,
+    /// Must also be a member of the guild for longer than 10 minutes.
+    High = 3,
+    /// Must also have a verified phone number.
+    VeryHigh = 4,
+}
+
 /// An enabled guild feature.
-#[derive(Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(from = "String")]
 pub enum GuildFeatures {
     /// Guild has access to set an invite splash background.
     InviteSplash,
@@ -113,7 +135,57 @@ pub enum GuildFeatures {
     AnimatedIcon,
     /// Guild has access to set a guild build banner image.
     Banner,
+    /// A feature that isn't known yet.
+    Other(String),
+}
+
+impl From<String> for GuildFeatures {
+    fn from(feature: String) -> Self {
+        match feature.as_str() {
+            "INVITE_SPLASH" => GuildFeatures::InviteSplash,
+            "VIP_REGIONS" => GuildFeatures::VipRegions,
+            "VANITY_URL" => GuildFeatures::VanityUrl,
+            "VERIFIED" => GuildFeatures::Verified,
+            "PARTNERED" => GuildFeatures::Partnered,
+            "PUBLIC" => GuildFeatures::Public,
+            "COMMERCE" => GuildFeatures::Commerce,
+            "NEWS" => GuildFeatures::News,
+            "DISCOVERABLE" => GuildFeatures::Discoverable,
+            "FEATURABLE" => GuildFeatures::Featurable,
+            "ANIMATED_ICON" => GuildFeatures::AnimatedIcon,
+            "BANNER" => GuildFeatures::Banner,
+            _ => GuildFeatures::Other(feature),
+        }
+    }
+}
+
+/// A preview of a guild that can be seen without being a member, if it is discoverable.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/guild#guild-preview-object)
+#[derive(Deserialize)]
+pub struct GuildPreview {
+    /// The id of the guild.
+    pub id: GuildId,
+    /// The name of the guild.
+    pub name: String,
+    /// Icon hash.
+    pub icon: Option<String>,
+    /// Splash hash.
+    pub splash: Option<String>,
+    /// Discovery splash hash.
+    pub discovery_splash: Option<String>,
+    /// Custom guild emojis.
+    pub emojis: Vec<Emoji>,
+    /// Enabled guild features.
+    pub features: Vec<GuildFeatures>,
+    /// Approximate number of members in the guild.
+    pub approximate_member_count: u32,
+    /// Approximate number of online members in the guild.
+    pub approximate_presence_count: u32,
+    /// The description for the guild.
+    pub description: Option<String>,
 }
+
 #[derive(Deserialize_repr)]
 #[repr(u8)]
 pub enum ExplicitContentFilterLevel {
@@ -136,4 +208,34 @@ pub enum PremiumTier {
     Tier1 = 1,
     Tier2 = 2,
     Tier3 = 3,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+    use super::super::RoleId;
+
+    #[test]
+    fn preview_with_unknown_features() {
+        let preview: GuildPreview = from_value(json!({
+            "id": "197038439483310086", "name": "Discord Testers", "icon": "f64c482b807da4f539cff778d174971c",
+            "splash": null, "discovery_splash": null,
+            "emojis": [{ "id": "41771983429993937", "name": "LUL", "roles": ["41771983429993000"], "animated": false }],
+            "features": ["DISCOVERABLE", "VANITY_URL", "ROLE_ICONS"],
+            "approximate_member_count": 60814, "approximate_presence_count": 20034,
+            "description": "The official place to report Discord Bugs!",
+        })).unwrap();
+        assert_eq!(preview.features, vec![
+            GuildFeatures::Discoverable, GuildFeatures::VanityUrl, GuildFeatures::Other("ROLE_ICONS".to_string()),
+        ]);
+        assert_eq!(preview.emojis[0].roles, vec![RoleId::from(41771983429993000)]);
+        assert_eq!(preview.approximate_member_count, 60814);
+    }
+
+    #[test]
+    fn verification_level() {
+        let levels: Vec<VerificationLevel> = from_value(json!([0, 2, 4])).unwrap();
+        assert_eq!(levels, vec![VerificationLevel::None, VerificationLevel::Medium, VerificationLevel::VeryHigh]);
+    }
+}
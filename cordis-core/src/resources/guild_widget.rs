@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ChannelId, GuildId};
+
+/// Whether a guild's widget is enabled and which channel it invites to.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/guild#guild-widget-settings-object)
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildWidgetSettings {
+    /// Whether the widget is enabled.
+    pub enabled: bool,
+    /// The id of the channel the widget will generate an invite to.
+    pub channel_id: Option<ChannelId>,
+}
+
+/// The public information about a guild shown by its widget.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/guild#guild-widget-object)
+#[derive(Deserialize)]
+pub struct GuildWidget {
+    /// The id of the guild.
+    pub id: GuildId,
+    /// The name of the guild.
+    pub name: String,
+    /// An invite to the guild, if the widget has an invite channel.
+    pub instant_invite: Option<String>,
+    /// The voice channels that everyone can see.
+    pub channels: Vec<WidgetChannel>,
+    /// Online members, which are anonymised and limited to 100.
+    pub members: Vec<WidgetMember>,
+    /// The number of online members.
+    pub presence_count: u32,
+}
+
+/// A voice channel shown in a guild's widget.
+#[derive(Deserialize)]
+pub struct WidgetChannel {
+    /// The id of the channel.
+    pub id: ChannelId,
+    /// The name of the channel.
+    pub name: String,
+    /// Sorting position of the channel.
+    pub position: u32,
+}
+
+/// An online member shown in a guild's widget.
+///
+/// The id is not the user's id, members are given an index instead.
+#[derive(Deserialize)]
+pub struct WidgetMember {
+    /// The anonymised id of the member.
+    pub id: String,
+    /// The member's username.
+    pub username: String,
+    /// Always `0000` as the discriminator is anonymised.
+    pub discriminator: String,
+    /// Always `None` as the avatar is anonymised.
+    pub avatar: Option<String>,
+    /// The member's status, e.g. `online`.
+    pub status: String,
+    /// The URL of the member's avatar.
+    pub avatar_url: String,
+    /// The voice channel the member is in.
+    pub channel_id: Option<ChannelId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+
+    #[test]
+    fn widget() {
+        let widget: GuildWidget = from_value(json!({
+            "id": "290926798626357999", "name": "Test Server", "instant_invite": "https://discord.com/invite/abc",
+            "channels": [{ "id": "705216630279993882", "name": "elephant", "position": 2 }],
+            "members": [{
+                "id": "0", "username": "1234", "discriminator": "0000", "avatar": null, "status": "online",
+                "avatar_url": "https://cdn.discordapp.com/widget-avatars/FfvURgcr3Za92K3JtoCppqnYMppMDc5B-Rll74YrGCU/C-1DyBZPQ6t5q2RuATFuMFgq0_uEMZVzd_6LbNXmMmQ",
+            }],
+            "presence_count": 1,
+        })).unwrap();
+        assert_eq!(widget.channels[0].id, ChannelId::from(705216630279993882));
+        assert_eq!(widget.members[0].channel_id, None);
+        assert_eq!(widget.presence_count, 1);
+    }
+
+    #[test]
+    fn settings() {
+        let settings: GuildWidgetSettings = from_value(json!({ "enabled": true, "channel_id": "41771983444115456" })).unwrap();
+        assert!(settings.enabled);
+        assert_eq!(settings.channel_id, Some(ChannelId::from(41771983444115456)));
+    }
+}
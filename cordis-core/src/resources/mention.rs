@@ -0,0 +1,234 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use super::{Channel, channel::ChannelCommon, ChannelId, Emoji, EmojiId, Role, RoleId, Snowflake, User, UserId};
+
+/// A piece of message content, either plain text or something Discord formats specially.
+///
+/// Displaying a token gives back the syntax used to write it in a message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Token<'a> {
+    /// Text that doesn't contain any mentions.
+    Text(&'a str),
+    /// A user mentioned with `<@id>`.
+    User(UserId),
+    /// A user mentioned with `<@!id>`, which shows their nickname.
+    UserNickname(UserId),
+    /// A channel mentioned with `<#id>`.
+    Channel(ChannelId),
+    /// A role mentioned with `<@&id>`.
+    Role(RoleId),
+    /// A custom emoji written as `<:name:id>`, or `<a:name:id>` if it is animated.
+    Emoji {
+        /// Whether the emoji is animated.
+        animated: bool,
+        /// The name of the emoji.
+        name: &'a str,
+        /// The id of the emoji.
+        id: EmojiId,
+    },
+    /// `@everyone`.
+    Everyone,
+    /// `@here`.
+    Here,
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Token::Text(text) => f.write_str(text),
+            Token::User(id) => write!(f, "<@{}>", id),
+            Token::UserNickname(id) => write!(f, "<@!{}>", id),
+            Token::Channel(id) => write!(f, "<#{}>", id),
+            Token::Role(id) => write!(f, "<@&{}>", id),
+            Token::Emoji { animated: true, name, id } => write!(f, "<a:{}:{}>", name, id),
+            Token::Emoji { animated: false, name, id } => write!(f, "<:{}:{}>", name, id),
+            Token::Everyone => f.write_str("@everyone"),
+            Token::Here => f.write_str("@here"),
+        }
+    }
+}
+
+/// Splits message content into [`Token`](enum.Token.html)s.
+pub fn tokenize(content: &str) -> Tokens<'_> {
+    Tokens { rest: content, next: None }
+}
+
+/// An iterator over the [`Token`](enum.Token.html)s in message content.
+pub struct Tokens<'a> {
+    rest: &'a str,
+    next: Option<(Token<'a>, usize)>,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        if let Some((token, length)) = self.next.take() {
+            self.rest = &self.rest[length..];
+            return Some(token);
+        }
+        if self.rest.is_empty() {
+            return None;
+        }
+        let found = self.rest.char_indices()
+            .filter(|(_, c)| *c == '<' || *c == '@')
+            .find_map(|(index, _)| parse_token(&self.rest[index..]).map(|token| (index, token)));
+        match found {
+            Some((0, (token, length))) => {
+                self.rest = &self.rest[length..];
+                Some(token)
+            },
+            Some((index, token)) => {
+                let text = &self.rest[..index];
+                self.rest = &self.rest[index..];
+                self.next = Some(token);
+                Some(Token::Text(text))
+            },
+            None => {
+                let text = self.rest;
+                self.rest = "";
+                Some(Token::Text(text))
+            },
+        }
+    }
+}
+
+/// Parses the token at the start of the text, returning it with the length of its syntax.
+pub(crate) fn parse_token(text: &str) -> Option<(Token<'_>, usize)> {
+    if text.starts_with("@everyone") {
+        return Some((Token::Everyone, "@everyone".len()));
+    }
+    if text.starts_with("@here") {
+        return Some((Token::Here, "@here".len()));
+    }
+    let inner = text.strip_prefix('<')?;
+    let inner = &inner[..inner.find('>')?];
+    let token = if let Some(id) = inner.strip_prefix("@!") {
+        Token::UserNickname(UserId(parse_id(id)?))
+    } else if let Some(id) = inner.strip_prefix("@&") {
+        Token::Role(RoleId(parse_id(id)?))
+    } else if let Some(id) = inner.strip_prefix('@') {
+        Token::User(UserId(parse_id(id)?))
+    } else if let Some(id) = inner.strip_prefix('#') {
+        Token::Channel(ChannelId(parse_id(id)?))
+    } else {
+        let (animated, emoji) = match inner.strip_prefix("a:") {
+            Some(emoji) => (true, emoji),
+            None => (false, inner.strip_prefix(':')?),
+        };
+        let (name, id) = emoji.split_at(emoji.find(':')?);
+        let valid_name = (2..=32).contains(&name.len()) && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_name {
+            return None;
+        }
+        Token::Emoji { animated, name, id: EmojiId(parse_id(&id[1..])?) }
+    };
+    Some((token, inner.len() + 2))
+}
+
+fn parse_id(id: &str) -> Option<Snowflake> {
+    if id.is_empty() || !id.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    id.parse().ok()
+}
+
+/// Something that can be mentioned in a message.
+pub trait Mention {
+    /// The token that mentions this, which can be displayed to get the syntax.
+    fn mention(&self) -> Token<'_>;
+}
+
+impl Mention for UserId {
+    fn mention(&self) -> Token<'_> {
+        Token::User(*self)
+    }
+}
+
+impl Mention for User {
+    fn mention(&self) -> Token<'_> {
+        self.id.mention()
+    }
+}
+
+impl Mention for RoleId {
+    fn mention(&self) -> Token<'_> {
+        Token::Role(*self)
+    }
+}
+
+impl Mention for Role {
+    fn mention(&self) -> Token<'_> {
+        self.id.mention()
+    }
+}
+
+impl Mention for ChannelId {
+    fn mention(&self) -> Token<'_> {
+        Token::Channel(*self)
+    }
+}
+
+impl Mention for Channel {
+    fn mention(&self) -> Token<'_> {
+        Token::Channel(self.id())
+    }
+}
+
+impl Mention for Emoji {
+    fn mention(&self) -> Token<'_> {
+        Token::Emoji {
+            animated: self.animated.unwrap_or(false),
+            // Discord finds the emoji by its id, the name only has to be valid.
+            name: self.name.as_deref().unwrap_or("emoji"),
+            id: self.id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(content: &str) -> Vec<Token<'_>> {
+        tokenize(content).collect()
+    }
+
+    #[test]
+    fn every_token() {
+        assert_eq!(tokens("hi <@1>, <@!2> in <#3> with <@&4> <:cordis:5><a:party_parrot:6> @everyone @here"), vec![
+            Token::Text("hi "),
+            Token::User(UserId::from(1)),
+            Token::Text(", "),
+            Token::UserNickname(UserId::from(2)),
+            Token::Text(" in "),
+            Token::Channel(ChannelId::from(3)),
+            Token::Text(" with "),
+            Token::Role(RoleId::from(4)),
+            Token::Text(" "),
+            Token::Emoji { animated: false, name: "cordis", id: EmojiId::from(5) },
+            Token::Emoji { animated: true, name: "party_parrot", id: EmojiId::from(6) },
+            Token::Text(" "),
+            Token::Everyone,
+            Token::Text(" "),
+            Token::Here,
+        ]);
+    }
+
+    #[test]
+    fn malformed_tokens_are_text() {
+        for content in ["<@abc>", "<@>", "<@1", "text <@", "<#-1>", "<:a:1>", "<:bad name:1>", "<:name:>", "<b:name:1>", "@every one", "<@&>"] {
+            assert_eq!(tokens(content), vec![Token::Text(content)], "{}", content);
+        }
+        assert_eq!(tokens("<<@1>>"), vec![Token::Text("<"), Token::User(UserId::from(1)), Token::Text(">")]);
+        assert!(tokens("").is_empty());
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let content = "😀 <@1><@!2> <#3> <@&4> <:cordis:5> <a:party:6> @everyone@here <@abc> done";
+        assert_eq!(tokenize(content).map(|token| token.to_string()).collect::<String>(), content);
+        assert_eq!(UserId::from(1).mention().to_string(), "<@1>");
+        assert_eq!(RoleId::from(4).mention().to_string(), "<@&4>");
+    }
+}
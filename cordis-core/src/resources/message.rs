@@ -2,11 +2,12 @@ use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use serde_repr::Deserialize_repr;
 
-use super::{Attachment, ChannelId, channel::ChannelType, Embed, Emoji, GuildId, RoleId, User};
+use super::{Attachment, ChannelId, channel::ChannelType, Embed, GuildId, Reaction, RoleId, StickerItem, TopLevelComponent, User};
 
-/// A snowflake if of a message.
-#[derive(Deserialize)]
-pub struct MessageId(String);
+snowflake_id! {
+    /// A snowflake id of a message.
+    MessageId
+}
 
 /// Represents a Discord message.
 #[derive(Deserialize)]
@@ -47,7 +48,8 @@ pub struct Message {
     /// Any embedded content.
     pub embeds: Vec<Embed>,
     /// Reactions to the message.
-    pub reactions: Vec<Emoji>,
+    #[serde(default)]
+    pub reactions: Vec<Reaction>,
     /// Used for validating a message was sent.
     pub nonce: Option<Nonce>,
     /// Whether this message is pinned.
@@ -61,6 +63,12 @@ pub struct Message {
     pub application: Option<MessageApplication>,
     /// Reference data sent with crossposted messages.
     pub message_reference: Option<MessageReference>,
+    /// The buttons and select menus attached to the message.
+    #[serde(default)]
+    pub components: Vec<TopLevelComponent>,
+    /// The stickers sent with the message.
+    #[serde(default)]
+    pub sticker_items: Vec<StickerItem>,
 }
 
 /// Used for validating a message was sent.
@@ -148,4 +156,31 @@ pub struct ChannelMention {
     pub type_: ChannelType,
     /// The name of the channel.
     pub name: String,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+    use super::super::{StickerFormatType, StickerId};
+
+    #[test]
+    fn sticker_items() {
+        let message: Message = from_value(json!({
+            "id": "1", "channel_id": "2", "guild_id": "3",
+            "author": { "id": "100", "username": "user", "discriminator": "0001", "avatar": null },
+            "content": "", "timestamp": "2022-01-01T00:00:00Z", "edited_timestamp": null, "tts": false,
+            "mention_everyone": false, "mentions": [], "mention_roles": [], "mention_channels": [],
+            "attachments": [], "embeds": [], "pinned": false, "type": 0,
+            "sticker_items": [
+                { "id": "749054660769218631", "name": "Wave", "format_type": 3 },
+                { "id": "60", "name": "New", "format_type": 9 },
+            ],
+        })).unwrap();
+        assert_eq!(message.sticker_items, vec![
+            StickerItem { id: StickerId::from(749054660769218631), name: "Wave".to_string(), format_type: StickerFormatType::Lottie },
+            StickerItem { id: StickerId::from(60), name: "New".to_string(), format_type: StickerFormatType::Unknown(9) },
+        ]);
+    }
+}
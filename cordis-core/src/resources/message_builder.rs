@@ -0,0 +1,297 @@
+use std::fmt::Display;
+
+use super::super::markdown::escape;
+use super::{tokenize, Mention, Token};
+
+/// The most characters the content of a message can have.
+pub const MAX_MESSAGE_LENGTH: usize = 2000;
+
+const FENCE: &str = "```";
+
+/// Composes the content of a message.
+///
+/// The content can be longer than a single message allows and split into several using
+/// [`split`](struct.MessageBuilder.html#method.split).
+#[derive(Clone, Debug, Default)]
+pub struct MessageBuilder(String);
+
+impl MessageBuilder {
+    /// Starts a message with no content.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the text as it is, so any formatting or mentions in it will work.
+    pub fn push(mut self, text: impl Display) -> Self {
+        self.0.push_str(&text.to_string());
+        self
+    }
+
+    /// Adds untrusted text with its formatting and mass mentions escaped.
+    pub fn push_safe(mut self, text: &str) -> Self {
+        self.0.push_str(&escape(text));
+        self
+    }
+
+    /// Starts a new line.
+    pub fn newline(mut self) -> Self {
+        self.0.push('\n');
+        self
+    }
+
+    /// Mentions a user, channel, role or emoji.
+    pub fn mention(mut self, item: &impl Mention) -> Self {
+        self.0.push_str(&item.mention().to_string());
+        self
+    }
+
+    /// Adds inline code, using double backticks if the code contains one.
+    pub fn code(mut self, code: &str) -> Self {
+        if code.contains('`') {
+            // The spaces stop backticks at the start or end of the code from closing it early.
+            self.0.push_str(&format!("`` {} ``", code));
+        } else {
+            self.0.push_str(&format!("`{}`", code));
+        }
+        self
+    }
+
+    /// Adds a fenced code block on its own lines, highlighted as the language if one is given.
+    pub fn code_block(mut self, language: Option<&str>, code: &str) -> Self {
+        self.start_line();
+        // A zero width space stops fences in the code from ending the block.
+        let code = code.replace(FENCE, "`\u{200B}``");
+        self.0.push_str(&format!("{}{}\n{}\n{}\n", FENCE, language.unwrap_or(""), code.trim_end_matches('\n'), FENCE));
+        self
+    }
+
+    /// Quotes every line of the text.
+    pub fn quote(mut self, text: &str) -> Self {
+        self.start_line();
+        for line in text.lines() {
+            self.0.push_str("> ");
+            self.0.push_str(line);
+            self.0.push('\n');
+        }
+        self
+    }
+
+    fn start_line(&mut self) {
+        if !self.0.is_empty() && !self.0.ends_with('\n') {
+            self.0.push('\n');
+        }
+    }
+
+    /// The content of the message, which may be too long to send in one message.
+    pub fn build(self) -> String {
+        self.0
+    }
+
+    /// Splits the content into messages of at most [`MAX_MESSAGE_LENGTH`](constant.MAX_MESSAGE_LENGTH.html) characters.
+    ///
+    /// Messages are split between lines where possible, then between words,
+    /// and never inside a mention or custom emoji.
+    /// A code block that is split is closed at the end of one message and reopened at the start of the next.
+    pub fn split(&self) -> Vec<String> {
+        let mut splitter = Splitter::default();
+        for line in self.0.split_inclusive('\n') {
+            splitter.push_line(line);
+        }
+        splitter.finish()
+    }
+}
+
+#[derive(Default)]
+struct Splitter {
+    messages: Vec<String>,
+    current: String,
+    length: usize,
+    /// The fence and language of the code block the content is currently in, used to reopen it.
+    fence: Option<String>,
+}
+
+impl Splitter {
+    /// The characters left for content, keeping enough to close an open code block.
+    fn space(&self) -> usize {
+        let reserved = if self.fence.is_some() { FENCE.len() + 1 } else { 0 };
+        MAX_MESSAGE_LENGTH.saturating_sub(self.length + reserved)
+    }
+
+    /// Whether the current message has anything besides a reopened code fence.
+    fn has_content(&self) -> bool {
+        let reopened = self.fence.as_ref().map_or(0, |fence| fence.chars().count() + 1);
+        self.length > reopened
+    }
+
+    fn push_line(&mut self, line: &str) {
+        let length = line.chars().count();
+        let toggles_fence = line.matches(FENCE).count() % 2 == 1;
+        // A line that opens a code block needs room for the fence that closes it as well.
+        let needed = if toggles_fence && self.fence.is_none() { length + FENCE.len() + 1 } else { length };
+        if needed > self.space() && self.has_content() {
+            self.flush();
+        }
+        if needed <= self.space() {
+            self.push(line, length);
+        } else {
+            for word in line.split_inclusive(char::is_whitespace) {
+                self.push_word(word);
+            }
+        }
+        if toggles_fence {
+            self.fence = match self.fence {
+                Some(_) => None,
+                None => Some(opening_fence(line)),
+            };
+        }
+    }
+
+    fn push_word(&mut self, word: &str) {
+        let length = word.chars().count();
+        if length > self.space() && self.has_content() {
+            self.flush();
+        }
+        if length <= self.space() {
+            return self.push(word, length);
+        }
+        for token in tokenize(word) {
+            match token {
+                Token::Text(text) => for (index, c) in text.char_indices() {
+                    self.push_unit(&text[index..index + c.len_utf8()]);
+                },
+                token => self.push_unit(&token.to_string()),
+            }
+        }
+    }
+
+    fn push_unit(&mut self, unit: &str) {
+        let length = unit.chars().count();
+        if length > self.space() {
+            self.flush();
+        }
+        self.push(unit, length);
+    }
+
+    fn push(&mut self, text: &str, length: usize) {
+        self.current.push_str(text);
+        self.length += length;
+    }
+
+    fn flush(&mut self) {
+        let mut message = std::mem::take(&mut self.current);
+        if self.fence.is_some() {
+            if !message.ends_with('\n') {
+                message.push('\n');
+            }
+            message.push_str(FENCE);
+        }
+        self.messages.push(message);
+        self.length = 0;
+        if let Some(fence) = self.fence.clone() {
+            self.push(&fence, fence.chars().count());
+            self.push("\n", 1);
+        }
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        if self.has_content() {
+            self.fence = None;
+            self.messages.push(self.current);
+        }
+        self.messages
+    }
+}
+
+/// The fence that opens a code block on the line and its language, leaving out any text before it.
+fn opening_fence(line: &str) -> String {
+    let start = line.rfind(FENCE).expect("Could not find the fence that opens the code block");
+    let language = line[start + FENCE.len()..].split(char::is_whitespace).next().unwrap_or_default();
+    format!("{}{}", FENCE, language)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(messages: &[String]) {
+        for message in messages {
+            assert!(message.chars().count() <= MAX_MESSAGE_LENGTH, "{} characters", message.chars().count());
+            assert_eq!(message.matches(FENCE).count() % 2, 0, "unclosed code block in {:?}", message);
+        }
+    }
+
+    #[test]
+    fn short_content_is_one_message() {
+        let builder = MessageBuilder::new().push("Hello").newline().code("x");
+        assert_eq!(builder.split(), vec!["Hello\n`x`".to_string()]);
+    }
+
+    #[test]
+    fn long_text_splits_between_lines_then_words() {
+        let line = format!("{}\n", "word ".repeat(100));
+        let builder = MessageBuilder::new().push(line.repeat(10));
+        let messages = builder.split();
+        check(&messages);
+        assert_eq!(messages.len(), 4);
+        assert!(messages.iter().all(|message| message.ends_with('\n')));
+        assert_eq!(messages.concat(), builder.build());
+
+        let builder = MessageBuilder::new().push("word ".repeat(1000));
+        let messages = builder.split();
+        check(&messages);
+        assert!(messages.iter().all(|message| message.ends_with("word ")));
+        assert_eq!(messages.concat(), builder.build());
+    }
+
+    #[test]
+    fn code_block_crossing_boundary() {
+        let code: Vec<String> = (0..300).map(|line| format!("let x{} = {};", line, line)).collect();
+        let builder = MessageBuilder::new().push("a".repeat(1990)).newline().code_block(Some("rust"), &code.join("\n"));
+        let messages = builder.split();
+        check(&messages);
+        assert_eq!(messages[0], format!("{}\n", "a".repeat(1990)));
+        assert!(messages[1].starts_with("```rust\nlet x0 = 0;\n"));
+        assert!(messages[1..].iter().all(|message| message.starts_with("```rust\n") && message.trim_end().ends_with(FENCE)));
+        let lines: Vec<&str> = messages[1..].iter()
+            .flat_map(|message| message.lines())
+            .filter(|line| !line.starts_with(FENCE))
+            .collect();
+        assert_eq!(lines, code);
+    }
+
+    #[test]
+    fn fence_opening_mid_line() {
+        let code: Vec<String> = (0..300).map(|line| format!("let x{} = {};", line, line)).collect();
+        let builder = MessageBuilder::new().push("see ``this`` then ```rust").newline().push(code.join("\n")).newline().push(FENCE);
+        let messages = builder.split();
+        check(&messages);
+        assert!(messages[0].starts_with("see ``this`` then ```rust\n"));
+        assert!(messages[1..].iter().all(|message| message.starts_with("```rust\nlet x")));
+        assert_eq!(messages.concat().matches("see").count(), 1);
+    }
+
+    #[test]
+    fn fence_opening_at_the_limit() {
+        let builder = MessageBuilder::new().push("a".repeat(1994)).newline().push("```\ncode\n```");
+        let messages = builder.split();
+        check(&messages);
+        assert_eq!(messages, vec![format!("{}\n", "a".repeat(1994)), "```\ncode\n```".to_string()]);
+    }
+
+    #[test]
+    fn tokens_are_not_split() {
+        for token in ["<@123456789012345678>", "<a:party_parrot:123456789012345678>", "@everyone"] {
+            let builder = MessageBuilder::new().push("a".repeat(1995)).push(token);
+            let messages = builder.split();
+            check(&messages);
+            assert_eq!(messages, vec!["a".repeat(1995), token.to_string()], "{}", token);
+        }
+    }
+
+    #[test]
+    fn multi_byte_characters_count_once() {
+        let builder = MessageBuilder::new().push("😀".repeat(2001));
+        let messages = builder.split();
+        assert_eq!(messages.iter().map(|message| message.chars().count()).collect::<Vec<_>>(), vec![2000, 1]);
+    }
+}
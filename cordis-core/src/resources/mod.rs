@@ -1,22 +1,61 @@
 //! Types that relate to common Discord elements like Messages and Channels.
 
+#[macro_use]
+mod snowflake;
+pub use snowflake::{DISCORD_EPOCH, Snowflake};
+mod allowed_mentions;
+pub use allowed_mentions::{AllowedMentions, AllowedMentionsError, MentionType};
+mod application;
+pub use application::{ApplicationId, CommandId, InteractionId, TeamId};
 mod attachment;
-pub use attachment::{Attachment, Dimensions};
+pub use attachment::{Attachment, AttachmentId, Dimensions};
+mod audit_log;
+pub use audit_log::{AuditEntryInfo, AuditLog, AuditLogChange, AuditLogEntry, AuditLogEntryId, AuditLogEvent, Change, PartialRole};
+mod ban;
+pub use ban::Ban;
 pub mod channel;
 pub use channel::{Channel, ChannelId};
+mod component;
+pub use component::{
+    ActionRow, Button, ButtonStyle, Component, ComponentError, ComponentType, MAX_ACTION_ROWS, MAX_BUTTONS_PER_ROW,
+    MAX_CUSTOM_ID_LENGTH, MAX_MODAL_TITLE_LENGTH, MAX_SELECT_OPTIONS, SelectMenu, SelectOption, TextInput, TextInputStyle,
+    TopLevelComponent, validate_components, validate_custom_id, validate_modal_components,
+};
 mod embed;
-pub use embed::Embed;
+pub use embed::{Embed, EmbedAuthor, EmbedBuilder, EmbedError, EmbedField, EmbedFooter, EmbedImage, EmbedProvider, EmbedVideo};
 mod emoji;
 pub use emoji::{Emoji, EmojiId};
+#[cfg(test)]
+mod fixtures;
 mod guild;
-pub use guild::{Guild, GuildId};
+pub use guild::{Guild, GuildFeatures, GuildId, GuildPreview, VerificationLevel};
 mod guild_member;
 pub use guild_member::GuildMember;
+mod guild_widget;
+pub use guild_widget::{GuildWidget, GuildWidgetSettings, WidgetChannel, WidgetMember};
+mod mention;
+pub use mention::{Mention, tokenize, Token, Tokens};
 mod message;
 pub use message::{Message, MessageId};
+mod message_builder;
+pub use message_builder::{MAX_MESSAGE_LENGTH, MessageBuilder};
+mod moderation;
+pub use moderation::{can_ban, can_edit_nickname, can_kick, can_manage_role, highest_role, Denied};
+mod permissions;
+pub use permissions::{channel_permissions, guild_permissions, OverwriteTarget, PermissionOverwrite, Permissions};
 mod reaction;
-pub use reaction::Reaction;
+pub use reaction::{Reaction, ReactionType, ReactionTypeError};
 mod role;
 pub use role::{Role, RoleId};
+mod scheduled_event;
+pub use scheduled_event::{GuildScheduledEvent, ScheduledEventEntityType, ScheduledEventId, ScheduledEventMetadata, ScheduledEventStatus};
+mod stage_instance;
+pub use stage_instance::{PrivacyLevel, StageInstance, StageInstanceId};
+mod sticker;
+pub use sticker::{Sticker, StickerFormatType, StickerId, StickerItem, StickerPackId, StickerType};
 mod user;
 pub use user::{PremiumType, User, UserId};
+mod voice_region;
+pub use voice_region::VoiceRegion;
+mod webhook;
+pub use webhook::{ExecuteWebhook, ExecuteWebhookBuilder, ExecuteWebhookError, Webhook, WebhookId, WebhookType, WebhookUrl, WebhookUrlError};
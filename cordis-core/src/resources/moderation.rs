@@ -0,0 +1,216 @@
+use std::cmp::Reverse;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use super::{guild_permissions, Guild, GuildMember, Permissions, Role};
+
+/// Why a member is not allowed to carry out a moderation action.
+#[derive(Debug, PartialEq)]
+pub enum Denied {
+    /// The member doesn't have the permissions needed.
+    MissingPermissions(Permissions),
+    /// The target is the owner of the guild, who can't be moderated.
+    TargetIsOwner,
+    /// Members can't carry out this action on themselves.
+    TargetIsSelf,
+    /// The member's highest role is not above the target's highest role.
+    Hierarchy {
+        /// The position of the member's highest role.
+        actor_position: u32,
+        /// The position of the target's highest role, or of the role being managed.
+        target_position: u32,
+    },
+}
+
+impl Display for Denied {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            Denied::MissingPermissions(permissions) => {
+                write!(f, "missing the permissions ")?;
+                bitflags::parser::to_writer(permissions, f)
+            },
+            Denied::TargetIsOwner => write!(f, "the owner of the guild can't be moderated"),
+            Denied::TargetIsSelf => write!(f, "members can't do this to themselves"),
+            Denied::Hierarchy { actor_position, target_position } => write!(
+                f, "the highest role (position {}) has to be above the target's role (position {})",
+                actor_position, target_position,
+            ),
+        }
+    }
+}
+
+impl Error for Denied {}
+
+/// The member's role that is highest in the role list, `None` if they only have `@everyone`.
+///
+/// If two roles have the same position, the one with the lower id is higher, like in Discord.
+pub fn highest_role<'a>(guild: &'a Guild, member: &GuildMember) -> Option<&'a Role> {
+    guild.roles.iter()
+        .filter(|role| member.roles.contains(&role.id))
+        .max_by(|a, b| a.position.cmp(&b.position).then(b.id.cmp(&a.id)))
+}
+
+
+fn is_owner(guild: &Guild, member: &GuildMember) -> bool {
+    member.user.id == guild.owner_id
+}
+
+fn require(guild: &Guild, actor: &GuildMember, permissions: Permissions) -> Result<(), Denied> {
+    let missing = permissions - guild_permissions(guild, actor);
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(Denied::MissingPermissions(missing))
+    }
+}
+
+/// Roles are ranked the same way as in [`highest_role`](fn.highest_role.html), with no role below every role.
+fn above(actor: Option<&Role>, target: Option<&Role>) -> Result<(), Denied> {
+    let rank = |role: Option<&Role>| role.map(|role| (role.position, Reverse(role.id)));
+    if rank(actor) > rank(target) {
+        Ok(())
+    } else {
+        let position = |role: Option<&Role>| role.map_or(0, |role| role.position);
+        Err(Denied::Hierarchy { actor_position: position(actor), target_position: position(target) })
+    }
+}
+
+fn can_moderate(guild: &Guild, actor: &GuildMember, target: &GuildMember, permissions: Permissions) -> Result<(), Denied> {
+    if actor.user.id == target.user.id {
+        return Err(Denied::TargetIsSelf);
+    }
+    if is_owner(guild, target) {
+        return Err(Denied::TargetIsOwner);
+    }
+    require(guild, actor, permissions)?;
+    if is_owner(guild, actor) {
+        return Ok(());
+    }
+    above(highest_role(guild, actor), highest_role(guild, target))
+}
+
+/// Whether the actor can add, remove or edit the role.
+///
+/// Requires `MANAGE_ROLES` and a highest role above the role, unless the actor owns the guild.
+pub fn can_manage_role(guild: &Guild, actor: &GuildMember, role: &Role) -> Result<(), Denied> {
+    require(guild, actor, Permissions::MANAGE_ROLES)?;
+    if is_owner(guild, actor) {
+        return Ok(());
+    }
+    above(highest_role(guild, actor), Some(role))
+}
+
+/// Whether the actor can kick the target from the guild.
+pub fn can_kick(guild: &Guild, actor: &GuildMember, target: &GuildMember) -> Result<(), Denied> {
+    can_moderate(guild, actor, target, Permissions::KICK_MEMBERS)
+}
+
+/// Whether the actor can ban the target from the guild.
+pub fn can_ban(guild: &Guild, actor: &GuildMember, target: &GuildMember) -> Result<(), Denied> {
+    can_moderate(guild, actor, target, Permissions::BAN_MEMBERS)
+}
+
+/// Whether the actor can change the target's nickname.
+///
+/// Members only need `CHANGE_NICKNAME` to change their own nickname,
+/// otherwise `MANAGE_NICKNAMES` and a higher role than the target is needed.
+pub fn can_edit_nickname(guild: &Guild, actor: &GuildMember, target: &GuildMember) -> Result<(), Denied> {
+    if actor.user.id == target.user.id {
+        return require(guild, actor, Permissions::CHANGE_NICKNAME);
+    }
+    can_moderate(guild, actor, target, Permissions::MANAGE_NICKNAMES)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::fixtures::{guild as guild_with, member, role};
+    use super::super::RoleId;
+
+    const EVERYONE: u64 = 1;
+    const HELPER: u64 = 2;
+    const MODERATOR: u64 = 3;
+    const OTHER_MODERATOR: u64 = 4;
+    const ADMIN: u64 = 5;
+    const HOIST: u64 = 6;
+    const OWNER: u64 = 100;
+
+    fn guild() -> Guild {
+        let moderator = Permissions::KICK_MEMBERS | Permissions::BAN_MEMBERS | Permissions::MANAGE_ROLES | Permissions::MANAGE_NICKNAMES;
+        guild_with(EVERYONE, OWNER, vec![
+            role(EVERYONE, 0, Permissions::CHANGE_NICKNAME),
+            role(HELPER, 1, Permissions::empty()),
+            role(MODERATOR, 2, moderator),
+            role(OTHER_MODERATOR, 2, moderator),
+            role(ADMIN, 3, Permissions::ADMINISTRATOR),
+            role(HOIST, 4, Permissions::empty()),
+        ])
+    }
+
+    #[test]
+    fn owner() {
+        let guild = guild();
+        let owner = member(OWNER, &[]);
+        let moderator = member(200, &[MODERATOR]);
+        assert_eq!(can_ban(&guild, &owner, &member(201, &[HOIST])), Ok(()));
+        assert_eq!(can_manage_role(&guild, &owner, &guild.roles[5]), Ok(()));
+        assert_eq!(can_kick(&guild, &moderator, &owner), Err(Denied::TargetIsOwner));
+        assert_eq!(can_ban(&guild, &member(201, &[ADMIN, HOIST]), &owner), Err(Denied::TargetIsOwner));
+    }
+
+    #[test]
+    fn yourself() {
+        let guild = guild();
+        let moderator = member(200, &[MODERATOR]);
+        assert_eq!(can_kick(&guild, &moderator, &moderator), Err(Denied::TargetIsSelf));
+        assert_eq!(can_ban(&guild, &member(OWNER, &[]), &member(OWNER, &[])), Err(Denied::TargetIsSelf));
+        assert_eq!(can_edit_nickname(&guild, &member(201, &[]), &member(201, &[])), Ok(()));
+    }
+
+    #[test]
+    fn equal_top_role() {
+        let guild = guild();
+        // Both roles are at position 2, so the one with the lower id ranks higher.
+        let moderator = member(200, &[HELPER, MODERATOR]);
+        let other = member(201, &[OTHER_MODERATOR]);
+        assert_eq!(can_kick(&guild, &moderator, &other), Ok(()));
+        assert_eq!(can_edit_nickname(&guild, &moderator, &other), Ok(()));
+        assert_eq!(can_manage_role(&guild, &moderator, &guild.roles[3]), Ok(()));
+        let denied = Err(Denied::Hierarchy { actor_position: 2, target_position: 2 });
+        assert_eq!(can_kick(&guild, &other, &moderator), denied);
+        assert_eq!(can_edit_nickname(&guild, &other, &moderator), denied);
+        assert_eq!(can_manage_role(&guild, &other, &guild.roles[2]), denied);
+        assert_eq!(can_manage_role(&guild, &moderator, &guild.roles[2]), denied);
+        assert_eq!(can_kick(&guild, &moderator, &member(202, &[MODERATOR])), denied);
+        let tied = member(202, &[MODERATOR, OTHER_MODERATOR]);
+        assert_eq!(highest_role(&guild, &tied).map(|role| role.id), Some(RoleId::from(MODERATOR)));
+    }
+
+    #[test]
+    fn higher_role() {
+        let guild = guild();
+        let moderator = member(200, &[MODERATOR]);
+        let helper = member(201, &[HELPER]);
+        assert_eq!(can_kick(&guild, &moderator, &helper), Ok(()));
+        assert_eq!(can_ban(&guild, &moderator, &member(202, &[])), Ok(()));
+        assert_eq!(can_manage_role(&guild, &moderator, &guild.roles[1]), Ok(()));
+        assert_eq!(can_kick(&guild, &helper, &member(202, &[])), Err(Denied::MissingPermissions(Permissions::KICK_MEMBERS)));
+        assert_eq!(can_ban(&guild, &member(203, &[MODERATOR, HOIST]), &member(204, &[ADMIN])), Ok(()));
+    }
+
+    #[test]
+    fn administrator() {
+        let guild = guild();
+        let admin = member(200, &[ADMIN]);
+        assert_eq!(can_ban(&guild, &admin, &member(201, &[MODERATOR])), Ok(()));
+        assert_eq!(can_manage_role(&guild, &admin, &guild.roles[2]), Ok(()));
+        assert_eq!(
+            can_kick(&guild, &admin, &member(201, &[HOIST])),
+            Err(Denied::Hierarchy { actor_position: 3, target_position: 4 }),
+        );
+        assert_eq!(
+            can_manage_role(&guild, &admin, &guild.roles[4]),
+            Err(Denied::Hierarchy { actor_position: 3, target_position: 3 }),
+        );
+    }
+}
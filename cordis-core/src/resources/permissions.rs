@@ -0,0 +1,311 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+use super::{Guild, GuildMember, RoleId, Snowflake, UserId};
+
+bitflags! {
+    /// A set of permissions that can be given to roles and members.
+    ///
+    /// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/topics/permissions#permissions-bitwise-permission-flags)
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+    pub struct Permissions: u64 {
+        /// Allows creation of instant invites.
+        const CREATE_INSTANT_INVITE = 1 << 0;
+        /// Allows kicking members.
+        const KICK_MEMBERS = 1 << 1;
+        /// Allows banning members.
+        const BAN_MEMBERS = 1 << 2;
+        /// Allows all permissions and bypasses channel permission overwrites.
+        const ADMINISTRATOR = 1 << 3;
+        /// Allows management and editing of channels.
+        const MANAGE_CHANNELS = 1 << 4;
+        /// Allows management and editing of the guild.
+        const MANAGE_GUILD = 1 << 5;
+        /// Allows for the addition of reactions to messages.
+        const ADD_REACTIONS = 1 << 6;
+        /// Allows for viewing of audit logs.
+        const VIEW_AUDIT_LOG = 1 << 7;
+        /// Allows for using priority speaker in a voice channel.
+        const PRIORITY_SPEAKER = 1 << 8;
+        /// Allows the user to go live.
+        const STREAM = 1 << 9;
+        /// Allows guild members to view a channel, which includes reading messages in text channels.
+        const VIEW_CHANNEL = 1 << 10;
+        /// Allows for sending messages in a channel.
+        const SEND_MESSAGES = 1 << 11;
+        /// Allows for sending of `/tts` messages.
+        const SEND_TTS_MESSAGES = 1 << 12;
+        /// Allows for deletion of other users messages.
+        const MANAGE_MESSAGES = 1 << 13;
+        /// Links sent by users with this permission will be auto-embedded.
+        const EMBED_LINKS = 1 << 14;
+        /// Allows for uploading images and files.
+        const ATTACH_FILES = 1 << 15;
+        /// Allows for reading of message history.
+        const READ_MESSAGE_HISTORY = 1 << 16;
+        /// Allows for using the `@everyone` and `@here` tags.
+        const MENTION_EVERYONE = 1 << 17;
+        /// Allows the usage of custom emojis from other servers.
+        const USE_EXTERNAL_EMOJIS = 1 << 18;
+        /// Allows for viewing guild insights.
+        const VIEW_GUILD_INSIGHTS = 1 << 19;
+        /// Allows for joining of a voice channel.
+        const CONNECT = 1 << 20;
+        /// Allows for speaking in a voice channel.
+        const SPEAK = 1 << 21;
+        /// Allows for muting members in a voice channel.
+        const MUTE_MEMBERS = 1 << 22;
+        /// Allows for deafening of members in a voice channel.
+        const DEAFEN_MEMBERS = 1 << 23;
+        /// Allows for moving of members between voice channels.
+        const MOVE_MEMBERS = 1 << 24;
+        /// Allows for using voice-activity-detection in a voice channel.
+        const USE_VAD = 1 << 25;
+        /// Allows for modification of own nickname.
+        const CHANGE_NICKNAME = 1 << 26;
+        /// Allows for modification of other users nicknames.
+        const MANAGE_NICKNAMES = 1 << 27;
+        /// Allows management and editing of roles.
+        const MANAGE_ROLES = 1 << 28;
+        /// Allows management and editing of webhooks.
+        const MANAGE_WEBHOOKS = 1 << 29;
+        /// Allows management and editing of emojis, stickers and soundboard sounds.
+        const MANAGE_GUILD_EXPRESSIONS = 1 << 30;
+        /// Allows members to use application commands.
+        const USE_APPLICATION_COMMANDS = 1 << 31;
+        /// Allows for requesting to speak in stage channels.
+        const REQUEST_TO_SPEAK = 1 << 32;
+        /// Allows for editing and deleting scheduled events.
+        const MANAGE_EVENTS = 1 << 33;
+        /// Allows for deleting and archiving threads, and viewing all private threads.
+        const MANAGE_THREADS = 1 << 34;
+        /// Allows for creating public and announcement threads.
+        const CREATE_PUBLIC_THREADS = 1 << 35;
+        /// Allows for creating private threads.
+        const CREATE_PRIVATE_THREADS = 1 << 36;
+        /// Allows the usage of custom stickers from other servers.
+        const USE_EXTERNAL_STICKERS = 1 << 37;
+        /// Allows for sending messages in threads.
+        const SEND_MESSAGES_IN_THREADS = 1 << 38;
+        /// Allows for using activities in a voice channel.
+        const USE_EMBEDDED_ACTIVITIES = 1 << 39;
+        /// Allows for timing out users.
+        const MODERATE_MEMBERS = 1 << 40;
+        /// Allows for viewing role subscription insights.
+        const VIEW_CREATOR_MONETIZATION_ANALYTICS = 1 << 41;
+        /// Allows for using the soundboard in a voice channel.
+        const USE_SOUNDBOARD = 1 << 42;
+        /// Allows for creating emojis, stickers and soundboard sounds.
+        const CREATE_GUILD_EXPRESSIONS = 1 << 43;
+        /// Allows for creating scheduled events.
+        const CREATE_EVENTS = 1 << 44;
+        /// Allows the usage of custom soundboard sounds from other servers.
+        const USE_EXTERNAL_SOUNDS = 1 << 45;
+        /// Allows sending voice messages.
+        const SEND_VOICE_MESSAGES = 1 << 46;
+        /// Allows sending polls.
+        const SEND_POLLS = 1 << 49;
+        /// Allows user-installed apps to send public responses.
+        const USE_EXTERNAL_APPS = 1 << 50;
+    }
+}
+
+impl Serialize for Permissions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for Permissions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        // Newer API versions send permissions as strings since they no longer fit in 53 bits.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Bits {
+            Integer(u64),
+            Text(String),
+        }
+        let bits = match Bits::deserialize(deserializer)? {
+            Bits::Integer(bits) => bits,
+            Bits::Text(text) => text.parse().map_err(D::Error::custom)?,
+        };
+        Ok(Permissions::from_bits_retain(bits))
+    }
+}
+
+/// Who a [`PermissionOverwrite`](struct.PermissionOverwrite.html) applies to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OverwriteTarget {
+    /// Everyone with the role, a role with the same id as the guild is `@everyone`.
+    Role(RoleId),
+    /// A single member.
+    Member(UserId),
+}
+
+/// Permissions allowed or denied on a channel, overriding those of the guild.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PermissionOverwrite {
+    /// The role or member this applies to.
+    pub target: OverwriteTarget,
+    /// The permissions that are allowed.
+    pub allow: Permissions,
+    /// The permissions that are denied.
+    pub deny: Permissions,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum OverwriteType {
+    Role,
+    Member,
+}
+
+#[derive(Deserialize)]
+struct InitialOverwrite {
+    id: Snowflake,
+    #[serde(rename = "type")]
+    type_: OverwriteType,
+    allow: Permissions,
+    deny: Permissions,
+}
+
+impl<'de> Deserialize<'de> for PermissionOverwrite {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let InitialOverwrite { id, type_, allow, deny } = InitialOverwrite::deserialize(d)?;
+        let target = match type_ {
+            OverwriteType::Role => OverwriteTarget::Role(RoleId(id)),
+            OverwriteType::Member => OverwriteTarget::Member(UserId(id)),
+        };
+        Ok(PermissionOverwrite { target, allow, deny })
+    }
+}
+
+/// The permissions a member has in a guild, not including channel overwrites.
+///
+/// The owner of the guild and members with `ADMINISTRATOR` have every permission,
+/// everyone else has the permissions of `@everyone` combined with those of their roles.
+pub fn guild_permissions(guild: &Guild, member: &GuildMember) -> Permissions {
+    if member.user.id == guild.owner_id {
+        return Permissions::all();
+    }
+    let everyone = RoleId(guild.id.0);
+    let permissions = guild.roles.iter()
+        .filter(|role| role.id == everyone || member.roles.contains(&role.id))
+        .fold(Permissions::empty(), |permissions, role| permissions | role.permissions);
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        Permissions::all()
+    } else {
+        permissions
+    }
+}
+
+/// The permissions a member has in a channel with the given overwrites.
+///
+/// Overwrites are applied in the same order as Discord:
+/// `@everyone`, then all of the member's roles at once, then the member.
+pub fn channel_permissions(guild: &Guild, member: &GuildMember, overwrites: &[PermissionOverwrite]) -> Permissions {
+    let mut permissions = guild_permissions(guild, member);
+    if permissions.contains(Permissions::ADMINISTRATOR) {
+        return Permissions::all();
+    }
+    let everyone = OverwriteTarget::Role(RoleId(guild.id.0));
+    if let Some(overwrite) = overwrites.iter().find(|overwrite| overwrite.target == everyone) {
+        permissions.remove(overwrite.deny);
+        permissions.insert(overwrite.allow);
+    }
+    let (allow, deny) = overwrites.iter()
+        .filter(|overwrite| match overwrite.target {
+            OverwriteTarget::Role(id) => member.roles.contains(&id),
+            OverwriteTarget::Member(_) => false,
+        })
+        .fold((Permissions::empty(), Permissions::empty()), |(allow, deny), overwrite| {
+            (allow | overwrite.allow, deny | overwrite.deny)
+        });
+    permissions.remove(deny);
+    permissions.insert(allow);
+    let member_target = OverwriteTarget::Member(member.user.id);
+    if let Some(overwrite) = overwrites.iter().find(|overwrite| overwrite.target == member_target) {
+        permissions.remove(overwrite.deny);
+        permissions.insert(overwrite.allow);
+    }
+    permissions
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+    use super::super::fixtures::{guild as guild_with, member, role};
+
+    const EVERYONE: u64 = 1;
+    const MODERATOR: u64 = 2;
+    const ADMIN: u64 = 3;
+    const MUTED: u64 = 4;
+    const OWNER: u64 = 100;
+
+    fn guild() -> Guild {
+        guild_with(EVERYONE, OWNER, vec![
+            role(EVERYONE, 0, Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES),
+            role(MODERATOR, 1, Permissions::KICK_MEMBERS | Permissions::MANAGE_MESSAGES),
+            role(ADMIN, 2, Permissions::ADMINISTRATOR),
+            role(MUTED, 3, Permissions::empty()),
+        ])
+    }
+
+    fn overwrites() -> Vec<PermissionOverwrite> {
+        from_value(json!([
+            { "id": EVERYONE.to_string(), "type": "role", "allow": 0, "deny": Permissions::SEND_MESSAGES.bits() },
+            { "id": MODERATOR.to_string(), "type": "role", "allow": Permissions::SEND_MESSAGES.bits(), "deny": 0 },
+            { "id": MUTED.to_string(), "type": "role", "allow": 0, "deny": Permissions::VIEW_CHANNEL.bits() },
+            { "id": "300", "type": "member", "allow": Permissions::VIEW_CHANNEL.bits(), "deny": 0 },
+        ])).unwrap()
+    }
+
+    #[test]
+    fn guild_permissions_table() {
+        let guild = guild();
+        let cases = [
+            ("owner", OWNER, vec![], Permissions::all()),
+            ("everyone", 200, vec![], Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES),
+            ("moderator", 200, vec![MODERATOR], Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES
+                | Permissions::KICK_MEMBERS | Permissions::MANAGE_MESSAGES),
+            ("administrator", 200, vec![ADMIN], Permissions::all()),
+        ];
+        for (name, user, roles, expected) in cases.iter() {
+            assert_eq!(guild_permissions(&guild, &member(*user, roles)), *expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn channel_permissions_table() {
+        let guild = guild();
+        let overwrites = overwrites();
+        let cases = [
+            ("owner", OWNER, vec![MUTED], Permissions::all()),
+            ("administrator", 200, vec![ADMIN, MUTED], Permissions::all()),
+            ("everyone overwrite", 200, vec![], Permissions::VIEW_CHANNEL),
+            ("role allow beats everyone deny", 200, vec![MODERATOR], Permissions::VIEW_CHANNEL
+                | Permissions::SEND_MESSAGES | Permissions::KICK_MEMBERS | Permissions::MANAGE_MESSAGES),
+            ("role deny", 200, vec![MUTED], Permissions::empty()),
+            ("role overwrites combined", 200, vec![MODERATOR, MUTED], Permissions::SEND_MESSAGES
+                | Permissions::KICK_MEMBERS | Permissions::MANAGE_MESSAGES),
+            ("member allow beats role deny", 300, vec![MUTED], Permissions::VIEW_CHANNEL),
+        ];
+        for (name, user, roles, expected) in cases.iter() {
+            assert_eq!(channel_permissions(&guild, &member(*user, roles), &overwrites), *expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn permissions_deserialize_from_integers_and_strings() {
+        let expected = Permissions::ADMINISTRATOR | Permissions::SEND_POLLS;
+        assert_eq!(from_value::<Permissions>(json!(expected.bits())).unwrap(), expected);
+        assert_eq!(from_value::<Permissions>(json!(expected.bits().to_string())).unwrap(), expected);
+    }
+}
@@ -1,6 +1,11 @@
-use serde::Deserialize;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
 
-use super::Emoji;
+use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::super::percent_encoding::{percent_decode, percent_encode};
+use super::{Emoji, EmojiId, mention::parse_token, Token};
 
 /// A reaction to a message.
 #[derive(Deserialize)]
@@ -10,5 +15,199 @@ pub struct Reaction {
     /// Whether the current user has reacted using this emoji.
     pub me: bool,
     /// The emoji used.
-    pub emoji: Emoji,
-}
\ No newline at end of file
+    pub emoji: ReactionType,
+}
+
+/// The emoji used to react to a message.
+///
+/// Displaying it gives the syntax used to write the emoji in a message.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ReactionType {
+    /// A standard unicode emoji, e.g. `👍`.
+    Unicode(String),
+    /// A custom emoji uploaded to a guild.
+    Custom {
+        /// The id of the emoji.
+        id: EmojiId,
+        /// The name of the emoji, `None` if the emoji was deleted.
+        name: Option<String>,
+        /// Whether the emoji is animated.
+        animated: bool,
+    },
+}
+
+impl ReactionType {
+    /// The form of the emoji used in the path of the reaction endpoints.
+    ///
+    /// Unicode emojis are percent-encoded and custom emojis are written as `name:id`.
+    pub fn to_url_component(&self) -> String {
+        match self {
+            ReactionType::Unicode(emoji) => percent_encode(emoji),
+            ReactionType::Custom { id, name, .. } => format!("{}:{}", percent_encode(name.as_deref().unwrap_or("_")), id),
+        }
+    }
+
+    /// Parses the form of the emoji used in the path of the reaction endpoints.
+    ///
+    /// Custom emojis parsed this way are never animated as the form doesn't say.
+    pub fn from_url_component(component: &str) -> Option<Self> {
+        let decoded = percent_decode(component)?;
+        if let Some((name, id)) = decoded.rsplit_once(':') {
+            if let Ok(id) = id.parse() {
+                let name = Some(name.to_string()).filter(|name| !name.is_empty());
+                return Some(ReactionType::Custom { id, name, animated: false });
+            }
+        }
+        Self::unicode(decoded)
+    }
+
+    /// Parses an emoji written in a message, either `<:name:id>`, `<a:name:id>` or a unicode emoji.
+    pub fn from_content(content: &str) -> Option<Self> {
+        match parse_token(content) {
+            Some((Token::Emoji { animated, name, id }, length)) if length == content.len() => {
+                Some(ReactionType::Custom { id, name: Some(name.to_string()), animated })
+            },
+            _ => Self::unicode(content.to_string()),
+        }
+    }
+
+    /// Unicode emojis can't be plain ASCII or contain whitespace.
+    fn unicode(emoji: String) -> Option<Self> {
+        if emoji.is_ascii() || emoji.contains(char::is_whitespace) {
+            None
+        } else {
+            Some(ReactionType::Unicode(emoji))
+        }
+    }
+}
+
+impl Display for ReactionType {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            ReactionType::Unicode(emoji) => f.write_str(emoji),
+            // Discord finds the emoji by its id, the name only has to be valid.
+            ReactionType::Custom { id, name, animated } => Token::Emoji {
+                animated: *animated,
+                name: name.as_deref().unwrap_or("emoji"),
+                id: *id,
+            }.fmt(f),
+        }
+    }
+}
+
+/// Why text is not an emoji that can be reacted with.
+#[derive(Debug, PartialEq)]
+pub enum ReactionTypeError {
+    /// The text is neither a custom emoji nor a unicode emoji.
+    InvalidEmoji(String),
+}
+
+impl Display for ReactionTypeError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            ReactionTypeError::InvalidEmoji(text) => write!(f, "{:?} is not a custom or unicode emoji", text),
+        }
+    }
+}
+
+impl Error for ReactionTypeError {}
+
+/// Parses the emoji the same way as [`from_content`](#method.from_content).
+impl FromStr for ReactionType {
+    type Err = ReactionTypeError;
+
+    fn from_str(content: &str) -> Result<Self, ReactionTypeError> {
+        Self::from_content(content).ok_or_else(|| ReactionTypeError::InvalidEmoji(content.to_string()))
+    }
+}
+
+impl From<&Emoji> for ReactionType {
+    fn from(emoji: &Emoji) -> Self {
+        ReactionType::Custom {
+            id: emoji.id,
+            name: emoji.name.clone(),
+            animated: emoji.animated.unwrap_or(false),
+        }
+    }
+}
+
+/// How a reaction emoji is sent by Discord, with a null id for unicode emojis.
+#[derive(Deserialize, Serialize)]
+struct InitialReactionType {
+    id: Option<EmojiId>,
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    animated: bool,
+}
+
+impl<'de> Deserialize<'de> for ReactionType {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let InitialReactionType { id, name, animated } = InitialReactionType::deserialize(d)?;
+        match id {
+            Some(id) => Ok(ReactionType::Custom { id, name, animated }),
+            None => name.map(ReactionType::Unicode).ok_or_else(|| D::Error::missing_field("name")),
+        }
+    }
+}
+
+impl Serialize for ReactionType {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let initial = match self.clone() {
+            ReactionType::Unicode(emoji) => InitialReactionType { id: None, name: Some(emoji), animated: false },
+            ReactionType::Custom { id, name, animated } => InitialReactionType { id: Some(id), name, animated },
+        };
+        initial.serialize(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json, to_value};
+
+    use super::*;
+
+    fn custom(id: u64, name: &str, animated: bool) -> ReactionType {
+        ReactionType::Custom { id: EmojiId::from(id), name: Some(name.to_string()), animated }
+    }
+
+    #[test]
+    fn serde() {
+        let unicode: ReactionType = from_value(json!({ "id": null, "name": "👍" })).unwrap();
+        assert_eq!(unicode, ReactionType::Unicode("👍".to_string()));
+        assert_eq!(to_value(&unicode).unwrap(), json!({ "id": null, "name": "👍" }));
+
+        let deleted: ReactionType = from_value(json!({ "id": "41771983429993937", "name": null })).unwrap();
+        assert_eq!(deleted, ReactionType::Custom { id: EmojiId::from(41771983429993937), name: None, animated: false });
+        let animated = custom(41771983429993937, "LUL", true);
+        assert_eq!(from_value::<ReactionType>(to_value(&animated).unwrap()).unwrap(), animated);
+
+        assert!(from_value::<ReactionType>(json!({ "id": null, "name": null })).is_err());
+    }
+
+    #[test]
+    fn url_component() {
+        let thumbs = ReactionType::Unicode("👍".to_string());
+        assert_eq!(thumbs.to_url_component(), "%F0%9F%91%8D");
+        assert_eq!(custom(1, "cordis", true).to_url_component(), "cordis:1");
+        for reaction in [thumbs, custom(1, "cordis", false)] {
+            assert_eq!(ReactionType::from_url_component(&reaction.to_url_component()), Some(reaction));
+        }
+        assert_eq!(
+            ReactionType::from_url_component("_:1"),
+            Some(ReactionType::Custom { id: EmojiId::from(1), name: Some("_".to_string()), animated: false }),
+        );
+        assert_eq!(ReactionType::from_url_component("thumbsup"), None);
+        assert_eq!(ReactionType::from_url_component("%zz"), None);
+    }
+
+    #[test]
+    fn from_str_and_display() {
+        for text in ["👍", "🏳️‍🌈", "<:cordis:1>", "<a:party_parrot:2>"] {
+            assert_eq!(text.parse::<ReactionType>().unwrap().to_string(), text);
+        }
+        assert_eq!("<a:party_parrot:2>".parse(), Ok(custom(2, "party_parrot", true)));
+        for text in ["", "thumbsup", "👍 👍", "<:cordis:1> ", "<@1>"] {
+            assert_eq!(text.parse::<ReactionType>(), Err(ReactionTypeError::InvalidEmoji(text.to_string())));
+        }
+    }
+}
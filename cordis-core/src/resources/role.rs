@@ -1,8 +1,11 @@
 use serde::Deserialize;
 
-/// The id of a role.
-#[derive(Deserialize)]
-pub struct RoleId(pub String);
+use super::Permissions;
+
+snowflake_id! {
+    /// The id of a role.
+    RoleId
+}
 
 /// A role that can be found in a guild.
 #[derive(Deserialize)]
@@ -18,7 +21,7 @@ pub struct Role {
     /// Position of this role.
     pub position: u32,
     /// Permission bit set.
-    pub permissions: u32,
+    pub permissions: Permissions,
     /// Whether this role is managed by an integration.
     pub managed: bool,
     /// Whether this role is mentionable.
@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use super::{ChannelId, GuildId, PrivacyLevel, Snowflake, User, UserId};
+
+snowflake_id! {
+    /// The id of a scheduled event.
+    ScheduledEventId
+}
+
+/// An event scheduled in a guild, held in a stage or voice channel or somewhere else.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object)
+#[derive(Deserialize)]
+pub struct GuildScheduledEvent {
+    /// The id of the event.
+    pub id: ScheduledEventId,
+    /// The id of the guild.
+    pub guild_id: GuildId,
+    /// The channel the event is held in, `None` for external events.
+    pub channel_id: Option<ChannelId>,
+    /// The id of the user that created the event.
+    pub creator_id: Option<UserId>,
+    /// The name of the event.
+    ///
+    /// (1-100 characters)
+    pub name: String,
+    /// The description of the event.
+    ///
+    /// (1-1000 characters)
+    pub description: Option<String>,
+    /// When the event starts.
+    pub scheduled_start_time: DateTime<Utc>,
+    /// When the event ends, which is always set for external events.
+    pub scheduled_end_time: Option<DateTime<Utc>>,
+    /// Who can see the event.
+    pub privacy_level: PrivacyLevel,
+    /// Whether the event has started or ended.
+    pub status: ScheduledEventStatus,
+    /// Where the event is held.
+    pub entity_type: ScheduledEventEntityType,
+    /// The id of the stage instance of the event.
+    pub entity_id: Option<Snowflake>,
+    /// Where an external event is held.
+    pub entity_metadata: Option<ScheduledEventMetadata>,
+    /// The user that created the event.
+    pub creator: Option<User>,
+    /// The number of users subscribed to the event.
+    pub user_count: Option<u32>,
+    /// The cover image hash of the event.
+    pub image: Option<String>,
+}
+
+/// Whether a scheduled event has started or ended.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(from = "u8")]
+pub enum ScheduledEventStatus {
+    /// The event hasn't started yet.
+    Scheduled,
+    /// The event is happening.
+    Active,
+    /// The event is over.
+    Completed,
+    /// The event was canceled before it started.
+    Canceled,
+    /// A status that isn't supported yet.
+    Unknown(u8),
+}
+
+impl From<u8> for ScheduledEventStatus {
+    fn from(code: u8) -> Self {
+        match code {
+            1 => ScheduledEventStatus::Scheduled,
+            2 => ScheduledEventStatus::Active,
+            3 => ScheduledEventStatus::Completed,
+            4 => ScheduledEventStatus::Canceled,
+            code => ScheduledEventStatus::Unknown(code),
+        }
+    }
+}
+
+/// Where a scheduled event is held.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(from = "u8")]
+pub enum ScheduledEventEntityType {
+    /// In a stage channel.
+    StageInstance,
+    /// In a voice channel.
+    Voice,
+    /// Somewhere outside of Discord.
+    External,
+    /// A type of location that isn't supported yet.
+    Unknown(u8),
+}
+
+impl From<u8> for ScheduledEventEntityType {
+    fn from(code: u8) -> Self {
+        match code {
+            1 => ScheduledEventEntityType::StageInstance,
+            2 => ScheduledEventEntityType::Voice,
+            3 => ScheduledEventEntityType::External,
+            code => ScheduledEventEntityType::Unknown(code),
+        }
+    }
+}
+
+/// Extra information about where a scheduled event is held.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ScheduledEventMetadata {
+    /// Where an external event is held.
+    ///
+    /// (1-100 characters)
+    pub location: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+
+    #[test]
+    fn unknown_values() {
+        let event: GuildScheduledEvent = from_value(json!({
+            "id": "1", "guild_id": "2", "channel_id": null, "creator_id": null, "name": "Meetup",
+            "scheduled_start_time": "2022-01-01T00:00:00Z", "scheduled_end_time": null,
+            "privacy_level": 3, "status": 5, "entity_type": 4, "entity_id": null,
+        })).unwrap();
+        assert_eq!(event.privacy_level, PrivacyLevel::Unknown(3));
+        assert_eq!(event.status, ScheduledEventStatus::Unknown(5));
+        assert_eq!(event.entity_type, ScheduledEventEntityType::Unknown(4));
+    }
+}
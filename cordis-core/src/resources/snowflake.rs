@@ -0,0 +1,165 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::{Error, Unexpected, Visitor};
+
+/// The first millisecond of 2015, which the timestamps of snowflakes are relative to.
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// A unique id used by Discord.
+///
+/// Discord sends these as strings in JSON but both strings and integers can be deserialized.
+/// They are always serialized as strings.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/reference#snowflakes)
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Snowflake(pub u64);
+
+impl Snowflake {
+    /// When this snowflake was created.
+    pub fn timestamp(self) -> DateTime<Utc> {
+        let millis = (self.0 >> 22) + DISCORD_EPOCH;
+        Utc.timestamp_millis_opt(millis as i64).unwrap()
+    }
+
+    /// The id of the internal worker that created this snowflake.
+    pub fn worker_id(self) -> u8 {
+        ((self.0 >> 17) & 0x1F) as u8
+    }
+
+    /// The id of the internal process that created this snowflake.
+    pub fn process_id(self) -> u8 {
+        ((self.0 >> 12) & 0x1F) as u8
+    }
+
+    /// Incremented for every snowflake generated on the process.
+    pub fn increment(self) -> u16 {
+        (self.0 & 0xFFF) as u16
+    }
+}
+
+impl Display for Snowflake {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for Snowflake {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Snowflake)
+    }
+}
+
+impl From<u64> for Snowflake {
+    fn from(id: u64) -> Self {
+        Snowflake(id)
+    }
+}
+
+impl Serialize for Snowflake {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Snowflake {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        struct SnowflakeVisitor;
+
+        impl<'de> Visitor<'de> for SnowflakeVisitor {
+            type Value = Snowflake;
+
+            fn expecting(&self, f: &mut Formatter) -> FmtResult {
+                write!(f, "a snowflake as a string or an integer")
+            }
+
+            fn visit_u64<E: Error>(self, v: u64) -> Result<Snowflake, E> {
+                Ok(Snowflake(v))
+            }
+
+            fn visit_i64<E: Error>(self, v: i64) -> Result<Snowflake, E> {
+                if v < 0 {
+                    Err(E::invalid_value(Unexpected::Signed(v), &self))
+                } else {
+                    Ok(Snowflake(v as u64))
+                }
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Snowflake, E> {
+                v.parse().map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(SnowflakeVisitor)
+    }
+}
+
+/// Declares a typed id that wraps a [`Snowflake`](struct.Snowflake.html).
+macro_rules! snowflake_id {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, serde::Deserialize, serde::Serialize)]
+        pub struct $name(pub $crate::resources::Snowflake);
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::num::ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.parse().map($name)
+            }
+        }
+
+        impl From<$crate::resources::Snowflake> for $name {
+            fn from(id: $crate::resources::Snowflake) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> Self {
+                $name($crate::resources::Snowflake(id))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_str, to_string};
+
+    use super::*;
+
+    #[test]
+    fn documented_example() {
+        let snowflake: Snowflake = "175928847299117063".parse().unwrap();
+        assert_eq!(snowflake.timestamp().timestamp_millis(), 1_462_015_105_796);
+        assert_eq!(snowflake.timestamp().to_rfc3339(), "2016-04-30T11:18:25.796+00:00");
+        assert_eq!(snowflake.worker_id(), 1);
+        assert_eq!(snowflake.process_id(), 0);
+        assert_eq!(snowflake.increment(), 7);
+    }
+
+    #[test]
+    fn serde() {
+        assert_eq!(from_str::<Snowflake>(r#""175928847299117063""#).unwrap(), Snowflake(175928847299117063));
+        assert_eq!(from_str::<Snowflake>("175928847299117063").unwrap(), Snowflake(175928847299117063));
+        assert!(from_str::<Snowflake>("-1").is_err());
+        assert!(from_str::<Snowflake>(r#""abc""#).is_err());
+        assert_eq!(to_string(&Snowflake(175928847299117063)).unwrap(), r#""175928847299117063""#);
+    }
+}
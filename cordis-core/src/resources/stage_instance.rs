@@ -0,0 +1,51 @@
+use serde::Deserialize;
+
+use super::{ChannelId, GuildId, ScheduledEventId};
+
+snowflake_id! {
+    /// The id of a stage instance.
+    StageInstanceId
+}
+
+/// A live stage in a stage channel.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/stage-instance#stage-instance-object)
+#[derive(Deserialize)]
+pub struct StageInstance {
+    /// The id of the stage instance.
+    pub id: StageInstanceId,
+    /// The id of the guild.
+    pub guild_id: GuildId,
+    /// The id of the stage channel.
+    pub channel_id: ChannelId,
+    /// The topic of the stage.
+    ///
+    /// (1-120 characters)
+    pub topic: String,
+    /// Who can see the stage.
+    pub privacy_level: PrivacyLevel,
+    /// The scheduled event the stage was started for.
+    pub guild_scheduled_event_id: Option<ScheduledEventId>,
+}
+
+/// Who can see a stage instance or scheduled event.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(from = "u8")]
+pub enum PrivacyLevel {
+    /// Visible publicly, which is no longer supported.
+    Public,
+    /// Only visible to the members of the guild.
+    GuildOnly,
+    /// A privacy level that isn't supported yet.
+    Unknown(u8),
+}
+
+impl From<u8> for PrivacyLevel {
+    fn from(code: u8) -> Self {
+        match code {
+            1 => PrivacyLevel::Public,
+            2 => PrivacyLevel::GuildOnly,
+            code => PrivacyLevel::Unknown(code),
+        }
+    }
+}
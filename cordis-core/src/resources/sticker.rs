@@ -0,0 +1,131 @@
+use serde::Deserialize;
+
+use super::{GuildId, User};
+
+snowflake_id! {
+    /// The id of a sticker.
+    StickerId
+}
+
+snowflake_id! {
+    /// The id of a pack of standard stickers.
+    StickerPackId
+}
+
+/// A sticker that can be sent in messages.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/sticker#sticker-object)
+#[derive(Deserialize)]
+pub struct Sticker {
+    /// The id of the sticker.
+    pub id: StickerId,
+    /// The pack the sticker is in, for standard stickers.
+    pub pack_id: Option<StickerPackId>,
+    /// The name of the sticker.
+    pub name: String,
+    /// The description of the sticker.
+    pub description: Option<String>,
+    /// Autocomplete and suggestion tags, the name of a unicode emoji for guild stickers.
+    pub tags: String,
+    /// Whether it is a standard or guild sticker.
+    #[serde(rename = "type")]
+    pub kind: StickerType,
+    /// The file format of the sticker.
+    pub format_type: StickerFormatType,
+    /// Whether the sticker can be used, which may be `false` when a guild loses boosts.
+    pub available: Option<bool>,
+    /// The guild the sticker was uploaded to, for guild stickers.
+    pub guild_id: Option<GuildId>,
+    /// The user that uploaded the sticker.
+    pub user: Option<User>,
+    /// The position of a standard sticker in its pack.
+    pub sort_value: Option<u32>,
+}
+
+/// The smallest amount of information needed to show a sticker, which is what messages have.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/sticker#sticker-item-object)
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct StickerItem {
+    /// The id of the sticker.
+    pub id: StickerId,
+    /// The name of the sticker.
+    pub name: String,
+    /// The file format of the sticker.
+    pub format_type: StickerFormatType,
+}
+
+/// Whether a sticker is a standard or guild sticker.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(from = "u8")]
+pub enum StickerType {
+    /// An official sticker in a pack.
+    Standard,
+    /// A sticker uploaded to a guild.
+    Guild,
+    /// A type of sticker that isn't supported yet.
+    Unknown(u8),
+}
+
+impl From<u8> for StickerType {
+    fn from(code: u8) -> Self {
+        match code {
+            1 => StickerType::Standard,
+            2 => StickerType::Guild,
+            code => StickerType::Unknown(code),
+        }
+    }
+}
+
+/// The file format of a sticker.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(from = "u8")]
+pub enum StickerFormatType {
+    Png,
+    Apng,
+    Lottie,
+    Gif,
+    /// A format that isn't supported yet.
+    Unknown(u8),
+}
+
+impl From<u8> for StickerFormatType {
+    fn from(code: u8) -> Self {
+        match code {
+            1 => StickerFormatType::Png,
+            2 => StickerFormatType::Apng,
+            3 => StickerFormatType::Lottie,
+            4 => StickerFormatType::Gif,
+            code => StickerFormatType::Unknown(code),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+
+    #[test]
+    fn guild_sticker() {
+        let sticker: Sticker = from_value(json!({
+            "id": "749054660769218631", "name": "Wave", "description": "Wumpus waves hello", "tags": "wumpus, hello",
+            "type": 2, "format_type": 3, "available": true, "guild_id": "1",
+            "user": { "id": "100", "username": "user", "discriminator": "0001", "avatar": null },
+        })).unwrap();
+        assert_eq!((sticker.kind, sticker.format_type), (StickerType::Guild, StickerFormatType::Lottie));
+        assert_eq!(sticker.guild_id, Some(GuildId::from(1)));
+        assert!(sticker.pack_id.is_none());
+    }
+
+    #[test]
+    fn unknown_types() {
+        let sticker: Sticker = from_value(json!({
+            "id": "1", "name": "New", "description": null, "tags": "new", "type": 3, "format_type": 9,
+        })).unwrap();
+        assert_eq!((sticker.kind, sticker.format_type), (StickerType::Unknown(3), StickerFormatType::Unknown(9)));
+        assert_eq!(from_value::<StickerType>(json!(1)).unwrap(), StickerType::Standard);
+    }
+}
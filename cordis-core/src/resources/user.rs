@@ -1,9 +1,10 @@
 use serde::Deserialize;
 use serde_repr::Deserialize_repr;
 
-/// Id snowflake of a User.
-#[derive(Deserialize)]
-pub struct UserId(pub String);
+snowflake_id! {
+    /// Id snowflake of a User.
+    UserId
+}
 
 /// A Discord User.
 #[derive(Deserialize)]
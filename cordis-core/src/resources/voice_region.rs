@@ -0,0 +1,36 @@
+use serde::Deserialize;
+
+/// A region that voice servers can be in.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/voice#voice-region-object)
+#[derive(Clone, Debug, Deserialize)]
+pub struct VoiceRegion {
+    /// The id of the region.
+    pub id: String,
+    /// The name of the region.
+    pub name: String,
+    /// Whether this is the closest region to the current user's client.
+    pub optimal: bool,
+    /// Whether the region is deprecated and should be avoided.
+    pub deprecated: bool,
+    /// Whether this is a custom region, used for events.
+    pub custom: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value, json};
+
+    use super::*;
+
+    #[test]
+    fn voice_regions() {
+        let regions: Vec<VoiceRegion> = from_value(json!([
+            { "id": "sydney", "name": "Sydney", "optimal": true, "deprecated": false, "custom": false },
+            { "id": "eu-central", "name": "Central Europe", "optimal": false, "deprecated": true, "custom": false },
+        ])).unwrap();
+        assert_eq!(regions[0].id, "sydney");
+        assert!(regions[0].optimal);
+        assert!(regions[1].deprecated);
+    }
+}
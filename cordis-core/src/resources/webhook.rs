@@ -0,0 +1,364 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_repr::Deserialize_repr;
+
+use super::{
+    ActionRow, AllowedMentions, AllowedMentionsError, ApplicationId, ChannelId, ComponentError, Embed, EmbedError, GuildId,
+    MAX_MESSAGE_LENGTH, TopLevelComponent, User, validate_components,
+};
+
+snowflake_id! {
+    /// The id of a webhook.
+    WebhookId
+}
+
+/// A low-effort way to post messages to a channel, which doesn't need a bot user.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/webhook#webhook-object)
+#[derive(Deserialize)]
+pub struct Webhook {
+    /// The id of the webhook.
+    pub id: WebhookId,
+    /// The type of webhook.
+    #[serde(rename = "type")]
+    pub type_: WebhookType,
+    /// The id of the guild the webhook is for.
+    pub guild_id: Option<GuildId>,
+    /// The id of the channel the webhook posts to.
+    pub channel_id: Option<ChannelId>,
+    /// The user that created the webhook.
+    ///
+    /// Not sent when the webhook is fetched with its token.
+    pub user: Option<User>,
+    /// The default name of the webhook.
+    pub name: Option<String>,
+    /// The default avatar hash of the webhook.
+    pub avatar: Option<String>,
+    /// The secure token of the webhook, only sent for incoming webhooks.
+    pub token: Option<String>,
+    /// The id of the bot application that created the webhook.
+    pub application_id: Option<ApplicationId>,
+    /// The URL used to execute the webhook, only sent for incoming webhooks.
+    pub url: Option<String>,
+}
+
+/// The type of a webhook.
+#[derive(Clone, Copy, Debug, Deserialize_repr, Eq, PartialEq)]
+#[repr(u8)]
+pub enum WebhookType {
+    /// Posts messages to a channel using its token.
+    Incoming = 1,
+    /// Posts messages crossposted from a followed news channel.
+    ChannelFollower = 2,
+    /// Used with interactions.
+    Application = 3,
+}
+
+/// The id and token of a webhook, which are all that's needed to execute it.
+///
+/// This is parsed from and displayed as the URL of the webhook, e.g. `https://discord.com/api/webhooks/{id}/{token}`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WebhookUrl {
+    /// The id of the webhook.
+    pub id: WebhookId,
+    /// The secure token of the webhook.
+    pub token: String,
+}
+
+/// Why a URL is not the URL of a webhook.
+#[derive(Debug, PartialEq)]
+pub enum WebhookUrlError {
+    /// The URL is not an http(s) URL of Discord.
+    NotDiscord,
+    /// The URL is not in the form `/api/webhooks/{id}/{token}`.
+    NotWebhook,
+    /// The id of the webhook is not a snowflake.
+    InvalidId(String),
+}
+
+impl Display for WebhookUrlError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            WebhookUrlError::NotDiscord => write!(f, "the URL is not a Discord URL"),
+            WebhookUrlError::NotWebhook => write!(f, "the URL is not in the form /api/webhooks/{{id}}/{{token}}"),
+            WebhookUrlError::InvalidId(id) => write!(f, "{:?} is not a valid webhook id", id),
+        }
+    }
+}
+
+impl Error for WebhookUrlError {}
+
+impl FromStr for WebhookUrl {
+    type Err = WebhookUrlError;
+
+    fn from_str(url: &str) -> Result<Self, WebhookUrlError> {
+        let rest = url.strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .ok_or(WebhookUrlError::NotDiscord)?;
+        let rest = rest.split(['?', '#']).next().unwrap_or_default();
+        let (host, path) = rest.split_once('/').ok_or(WebhookUrlError::NotWebhook)?;
+        let host = host.strip_prefix("canary.").or_else(|| host.strip_prefix("ptb.")).unwrap_or(host);
+        if !matches!(host, "discord.com" | "discordapp.com") {
+            return Err(WebhookUrlError::NotDiscord);
+        }
+        let mut segments = path.trim_end_matches('/').split('/').peekable();
+        if segments.next() != Some("api") {
+            return Err(WebhookUrlError::NotWebhook);
+        }
+        let versioned = segments.peek()
+            .and_then(|segment| segment.strip_prefix('v'))
+            .is_some_and(|version| !version.is_empty() && version.bytes().all(|b| b.is_ascii_digit()));
+        if versioned {
+            segments.next();
+        }
+        match (segments.next(), segments.next(), segments.next(), segments.next()) {
+            (Some("webhooks"), Some(id), Some(token), None) if !token.is_empty() => Ok(WebhookUrl {
+                id: id.parse().map_err(|_| WebhookUrlError::InvalidId(id.to_string()))?,
+                token: token.to_string(),
+            }),
+            _ => Err(WebhookUrlError::NotWebhook),
+        }
+    }
+}
+
+impl Display for WebhookUrl {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "https://discord.com/api/webhooks/{}/{}", self.id, self.token)
+    }
+}
+
+/// The most embeds a single message can have.
+const EMBED_LIMIT: usize = 10;
+/// The most characters all the embeds of a message can have together.
+const EMBEDS_TOTAL_LIMIT: usize = 6000;
+
+/// The body of a request that posts a message using a webhook.
+///
+/// [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/resources/webhook#execute-webhook)
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ExecuteWebhook {
+    /// The content of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Overrides the default name of the webhook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Overrides the default avatar of the webhook.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    /// Whether this is a TTS message.
+    pub tts: bool,
+    /// Embedded rich content.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub embeds: Vec<Embed>,
+    /// Which mentions in the content are allowed to notify anyone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+    /// The buttons and select menus attached to the message.
+    ///
+    /// Only webhooks created by an application can send components.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<TopLevelComponent>,
+}
+
+impl ExecuteWebhook {
+    /// Starts building a message to post that is validated when it is built.
+    pub fn builder() -> ExecuteWebhookBuilder {
+        ExecuteWebhookBuilder(Self::default())
+    }
+
+    /// Checks that Discord will accept the message.
+    pub fn validate(&self) -> Result<(), ExecuteWebhookError> {
+        if self.content.as_deref().is_none_or(str::is_empty) && self.embeds.is_empty() && self.components.is_empty() {
+            return Err(ExecuteWebhookError::Empty);
+        }
+        let length = self.content.as_deref().map_or(0, |content| content.chars().count());
+        if length > MAX_MESSAGE_LENGTH {
+            return Err(ExecuteWebhookError::ContentTooLong(length));
+        }
+        if self.embeds.len() > EMBED_LIMIT {
+            return Err(ExecuteWebhookError::TooManyEmbeds(self.embeds.len()));
+        }
+        for (index, embed) in self.embeds.iter().enumerate() {
+            embed.validate().map_err(|error| ExecuteWebhookError::Embed { index, error })?;
+        }
+        let length = self.embeds.iter().map(Embed::length).sum();
+        if length > EMBEDS_TOTAL_LIMIT {
+            return Err(ExecuteWebhookError::EmbedsTooLong(length));
+        }
+        if let Some(allowed_mentions) = &self.allowed_mentions {
+            allowed_mentions.validate().map_err(ExecuteWebhookError::AllowedMentions)?;
+        }
+        validate_components(&self.components).map_err(ExecuteWebhookError::Components)
+    }
+}
+
+/// Why Discord would not accept a message posted using a webhook.
+#[derive(Debug, PartialEq)]
+pub enum ExecuteWebhookError {
+    /// The message has no content, embeds or components.
+    Empty,
+    /// The content is longer than 2000 characters.
+    ContentTooLong(usize),
+    /// There are more than 10 embeds.
+    TooManyEmbeds(usize),
+    /// An embed broke one of its limits.
+    Embed {
+        /// The index of the embed.
+        index: usize,
+        /// The limit that was broken.
+        error: EmbedError,
+    },
+    /// The embeds have more than 6000 characters together.
+    EmbedsTooLong(usize),
+    /// The allowed mentions would be rejected.
+    AllowedMentions(AllowedMentionsError),
+    /// The components broke one of their limits.
+    Components(ComponentError),
+}
+
+impl Display for ExecuteWebhookError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self {
+            ExecuteWebhookError::Empty => write!(f, "the message needs content, an embed or a component"),
+            ExecuteWebhookError::ContentTooLong(length) => write!(f, "the content has {} characters but the limit is {}", length, MAX_MESSAGE_LENGTH),
+            ExecuteWebhookError::TooManyEmbeds(count) => write!(f, "there are {} embeds but the limit is {}", count, EMBED_LIMIT),
+            ExecuteWebhookError::Embed { index, error } => write!(f, "embed {}: {}", index, error),
+            ExecuteWebhookError::EmbedsTooLong(length) => write!(f, "the embeds have {} characters together but the limit is {}", length, EMBEDS_TOTAL_LIMIT),
+            ExecuteWebhookError::AllowedMentions(error) => error.fmt(f),
+            ExecuteWebhookError::Components(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for ExecuteWebhookError {}
+
+/// Builds an [`ExecuteWebhook`](struct.ExecuteWebhook.html).
+pub struct ExecuteWebhookBuilder(ExecuteWebhook);
+
+impl ExecuteWebhookBuilder {
+    /// Sets the content of the message.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.0.content = Some(content.into());
+        self
+    }
+
+    /// Overrides the default name of the webhook.
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.0.username = Some(username.into());
+        self
+    }
+
+    /// Overrides the default avatar of the webhook.
+    pub fn avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
+        self.0.avatar_url = Some(avatar_url.into());
+        self
+    }
+
+    /// Sets whether this is a TTS message.
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.0.tts = tts;
+        self
+    }
+
+    /// Adds an embed.
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.0.embeds.push(embed);
+        self
+    }
+
+    /// Sets which mentions in the content are allowed to notify anyone.
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.0.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    /// Adds a row of components.
+    pub fn action_row(mut self, row: ActionRow) -> Self {
+        self.0.components.push(row.into());
+        self
+    }
+
+    /// Checks that Discord will accept the message and returns it.
+    pub fn build(self) -> Result<ExecuteWebhook, ExecuteWebhookError> {
+        self.0.validate()?;
+        Ok(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::{ActionRow, AllowedMentions, Button, ButtonStyle, MentionType, UserId};
+
+    const TOKEN: &str = "3d89bb7572e0fb30d8128367b3b1b44fecd1726de135cbe28a41f8b2f777c372ba2939e72279b94526ff5d1bd4358d65cf11";
+
+    fn url(id: u64) -> WebhookUrl {
+        WebhookUrl { id: WebhookId::from(id), token: TOKEN.to_string() }
+    }
+
+    #[test]
+    fn parses_webhook_urls() {
+        let urls = [
+            "https://discord.com/api/webhooks/223704706495545344/{}",
+            "https://discordapp.com/api/webhooks/223704706495545344/{}",
+            "https://canary.discord.com/api/webhooks/223704706495545344/{}",
+            "https://ptb.discord.com/api/v10/webhooks/223704706495545344/{}",
+            "http://discord.com/api/webhooks/223704706495545344/{}/",
+            "https://discord.com/api/webhooks/223704706495545344/{}?wait=true",
+        ];
+        for text in urls.iter().map(|text| text.replace("{}", TOKEN)) {
+            assert_eq!(text.parse(), Ok(url(223704706495545344)), "{}", text);
+        }
+        assert_eq!(url(1).to_string(), format!("https://discord.com/api/webhooks/1/{}", TOKEN));
+        assert_eq!(url(1).to_string().parse(), Ok(url(1)));
+    }
+
+    #[test]
+    fn rejects_other_urls() {
+        let cases = [
+            ("discord.com/api/webhooks/1/token", WebhookUrlError::NotDiscord),
+            ("https://example.com/api/webhooks/1/token", WebhookUrlError::NotDiscord),
+            ("https://discord.com.example.com/api/webhooks/1/token", WebhookUrlError::NotDiscord),
+            ("https://discord.com/api/webhooks/1", WebhookUrlError::NotWebhook),
+            ("https://discord.com/api/webhooks/1/", WebhookUrlError::NotWebhook),
+            ("https://discord.com/api/webhooks/1/token/github", WebhookUrlError::NotWebhook),
+            ("https://discord.com/api/channels/1/token", WebhookUrlError::NotWebhook),
+            ("https://discord.com", WebhookUrlError::NotWebhook),
+            ("https://discord.com/api/webhooks/abc/token", WebhookUrlError::InvalidId("abc".to_string())),
+        ];
+        for (text, error) in cases.iter() {
+            assert_eq!(text.parse::<WebhookUrl>().as_ref(), Err(error), "{}", text);
+        }
+    }
+
+    #[test]
+    fn validate() {
+        assert_eq!(ExecuteWebhook::builder().build().err(), Some(ExecuteWebhookError::Empty));
+        assert_eq!(ExecuteWebhook::builder().content("").build().err(), Some(ExecuteWebhookError::Empty));
+        assert!(ExecuteWebhook::builder().content("a".repeat(2000)).build().is_ok());
+        assert_eq!(ExecuteWebhook::builder().content("a".repeat(2001)).build().err(), Some(ExecuteWebhookError::ContentTooLong(2001)));
+        let button = Button::new(ButtonStyle::Primary, "id", "Click");
+        assert!(ExecuteWebhook::builder().action_row(ActionRow::new().component(button)).build().is_ok());
+
+        let embeds = |count| (0..count).fold(ExecuteWebhook::builder(), |builder, _| builder.embed(Embed::builder().title("title").build().unwrap()));
+        assert!(embeds(10).build().is_ok());
+        assert_eq!(embeds(11).build().err(), Some(ExecuteWebhookError::TooManyEmbeds(11)));
+        let invalid = Embed { title: Some("a".repeat(257)), ..Embed::default() };
+        assert_eq!(
+            ExecuteWebhook::builder().embed(Embed::default()).embed(invalid).build().err(),
+            Some(ExecuteWebhookError::Embed { index: 1, error: EmbedError::TitleTooLong(257) }),
+        );
+        let long = || Embed::builder().description("a".repeat(2001)).build().unwrap();
+        assert_eq!(ExecuteWebhook::builder().embed(long()).embed(long()).embed(long()).build().err(), Some(ExecuteWebhookError::EmbedsTooLong(6003)));
+
+        let mentions = AllowedMentions::default().parse(MentionType::Users).user(UserId::from(1));
+        assert_eq!(
+            ExecuteWebhook::builder().content("<@1>").allowed_mentions(mentions).build().err(),
+            Some(ExecuteWebhookError::AllowedMentions(AllowedMentionsError::UsersAlreadyParsed)),
+        );
+    }
+}
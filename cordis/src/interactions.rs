@@ -0,0 +1,400 @@
+//! An HTTP endpoint that receives interactions from Discord, for bots that don't connect to the gateway.
+//!
+//! [`InteractionEndpoint::handle`](struct.InteractionEndpoint.html#method.handle) can be called from any hyper service
+//! running on a tokio runtime, which deferred follow ups are spawned on,
+//! or [`InteractionEndpoint::serve`](struct.InteractionEndpoint.html#method.serve) can be used to run a server by itself.
+//!
+//! [See the official Discord documentation for more information.](https://discordapp.com/developers/docs/interactions/receiving-and-responding#receiving-an-interaction)
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::error::Error;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use cordis_core::interactions::{
+    Interaction, InteractionData, InteractionResponse, InteractionType, SIGNATURE_HEADER, TIMESTAMP_HEADER, Verifier,
+    VerifyError,
+};
+use http_body_util::{BodyExt, Full, Limited};
+use hyper::body::{Body, Bytes, Frame, SizeHint};
+use hyper::header::{CONTENT_TYPE, HeaderMap};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use tokio::runtime::Handle;
+
+/// The largest request body that is accepted, far larger than any interaction Discord sends.
+const MAX_BODY_LENGTH: usize = 1 << 20;
+/// How long to wait before accepting connections again after it failed, like when there are no file descriptors left.
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+type Handler = Box<dyn Fn(Interaction) -> BoxFuture<Reply> + Send + Sync>;
+
+/// What a handler replies to an interaction with.
+pub enum Reply {
+    /// Responds straight away.
+    Respond(InteractionResponse),
+    /// Acknowledges the interaction so the user sees a loading state, then runs the follow up.
+    ///
+    /// The follow up has 15 minutes to edit the original response using the token of the interaction.
+    Defer {
+        /// Whether the response that follows is only visible to the user.
+        ephemeral: bool,
+        /// Run in the background once the acknowledgement has been written.
+        follow_up: BoxFuture<()>,
+    },
+}
+
+impl Reply {
+    /// Acknowledges the interaction, then runs `follow_up` in the background once the acknowledgement has been written.
+    pub fn defer(ephemeral: bool, follow_up: impl Future<Output = ()> + Send + 'static) -> Self {
+        Reply::Defer { ephemeral, follow_up: Box::pin(follow_up) }
+    }
+}
+
+impl From<InteractionResponse> for Reply {
+    fn from(response: InteractionResponse) -> Self {
+        Reply::Respond(response)
+    }
+}
+
+/// Verifies the requests Discord sends to the interactions endpoint URL and routes them to handlers.
+///
+/// Commands are routed by their name, and every other interaction, like components and modals,
+/// goes to the fallback handler.
+pub struct InteractionEndpoint {
+    verifier: Verifier,
+    commands: HashMap<String, Handler>,
+    fallback: Option<Handler>,
+}
+
+impl InteractionEndpoint {
+    /// Creates an endpoint for the application with the hex encoded public key shown in the developer portal.
+    pub fn new(public_key: &str) -> Result<Self, VerifyError> {
+        Ok(Self {
+            verifier: Verifier::new(public_key)?,
+            commands: HashMap::new(),
+            fallback: None,
+        })
+    }
+
+    /// Handles the command with the name, including its autocomplete interactions.
+    pub fn command<F, R>(mut self, name: impl Into<String>, handler: F) -> Self
+        where F: Fn(Interaction) -> R + Send + Sync + 'static,
+              R: Future<Output = Reply> + Send + 'static,
+    {
+        self.commands.insert(name.into(), boxed(handler));
+        self
+    }
+
+    /// Handles the interactions that no command handler was registered for.
+    pub fn fallback<F, R>(mut self, handler: F) -> Self
+        where F: Fn(Interaction) -> R + Send + Sync + 'static,
+              R: Future<Output = Reply> + Send + 'static,
+    {
+        self.fallback = Some(boxed(handler));
+        self
+    }
+
+    /// Handles a request sent by Discord, replying with the response of the handler.
+    ///
+    /// Requests with a bad signature get a `401 Unauthorized`, which Discord checks for.
+    /// Follow ups of deferred replies are spawned on the tokio runtime when the body of the response is dropped,
+    /// which hyper does once it has written it, so they can't edit the response before Discord has it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a handler defers its reply when this isn't called from a tokio runtime.
+    pub async fn handle<B>(&self, request: Request<B>) -> Response<ResponseBody>
+        where B: Body,
+              B::Error: Into<Box<dyn Error + Send + Sync>>,
+    {
+        if request.method() != Method::POST {
+            return error(StatusCode::METHOD_NOT_ALLOWED, "interactions must be POSTed");
+        }
+        let (parts, body) = request.into_parts();
+        let body = match Limited::new(body, MAX_BODY_LENGTH).collect().await {
+            Ok(body) => body.to_bytes(),
+            Err(_) => return error(StatusCode::BAD_REQUEST, "could not read the body"),
+        };
+        if self.verify(&parts.headers, &body).is_err() {
+            return error(StatusCode::UNAUTHORIZED, "invalid request signature");
+        }
+        let interaction: Interaction = match serde_json::from_slice(&body) {
+            Ok(interaction) => interaction,
+            Err(_) => return error(StatusCode::BAD_REQUEST, "the body is not an interaction"),
+        };
+        if interaction.kind == InteractionType::Ping {
+            return respond(&InteractionResponse::pong());
+        }
+        let handler = match &interaction.data {
+            Some(InteractionData::Command(data)) => self.commands.get(&data.name),
+            _ => None,
+        };
+        let handler = match handler.or(self.fallback.as_ref()) {
+            Some(handler) => handler,
+            None => return error(StatusCode::NOT_FOUND, "no handler for the interaction"),
+        };
+        match handler(interaction).await {
+            Reply::Respond(response) => respond(&response),
+            Reply::Defer { ephemeral, follow_up } => respond(&InteractionResponse::deferred(ephemeral))
+                .map(|body| body.then(follow_up)),
+        }
+    }
+
+    /// Serves the endpoint over HTTP/1 on every connection accepted by the listener, until the future is dropped.
+    ///
+    /// Connections that fail to be accepted are skipped, waiting a moment in case the error lasts.
+    pub async fn serve(self, listener: TcpListener) {
+        let endpoint = Arc::new(self);
+        loop {
+            let stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(_) => {
+                    tokio::time::sleep(ACCEPT_RETRY_DELAY).await;
+                    continue;
+                },
+            };
+            let endpoint = endpoint.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |request| {
+                    let endpoint = endpoint.clone();
+                    async move { Ok::<_, Infallible>(endpoint.handle(request).await) }
+                });
+                // A connection closing early only affects that request.
+                let _ = http1::Builder::new().serve_connection(TokioIo::new(stream), service).await;
+            });
+        }
+    }
+
+    fn verify(&self, headers: &HeaderMap, body: &[u8]) -> Result<(), VerifyError> {
+        let header = |name| headers.get(name).and_then(|value| value.to_str().ok());
+        match (header(SIGNATURE_HEADER), header(TIMESTAMP_HEADER)) {
+            (Some(signature), Some(timestamp)) => self.verifier.verify(signature, timestamp, body),
+            _ => Err(VerifyError::MalformedSignature),
+        }
+    }
+}
+
+/// The body of a response from the endpoint, which starts the follow up of a deferred reply once it is dropped.
+pub struct ResponseBody {
+    data: Full<Bytes>,
+    follow_up: Option<(Handle, BoxFuture<()>)>,
+}
+
+impl ResponseBody {
+    fn then(mut self, follow_up: BoxFuture<()>) -> Self {
+        let runtime = Handle::try_current().expect("Deferred replies have to be handled on a tokio runtime");
+        self.follow_up = Some((runtime, follow_up));
+        self
+    }
+}
+
+impl From<Bytes> for ResponseBody {
+    fn from(data: Bytes) -> Self {
+        Self { data: Full::new(data), follow_up: None }
+    }
+}
+
+impl Body for ResponseBody {
+    type Data = Bytes;
+    type Error = Infallible;
+
+    fn poll_frame(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Result<Frame<Bytes>, Infallible>>> {
+        Pin::new(&mut self.data).poll_frame(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.data.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.data.size_hint()
+    }
+}
+
+impl Drop for ResponseBody {
+    fn drop(&mut self) {
+        if let Some((runtime, follow_up)) = self.follow_up.take() {
+            runtime.spawn(follow_up);
+        }
+    }
+}
+
+fn boxed<F, R>(handler: F) -> Handler
+    where F: Fn(Interaction) -> R + Send + Sync + 'static,
+          R: Future<Output = Reply> + Send + 'static,
+{
+    Box::new(move |interaction| Box::pin(handler(interaction)))
+}
+
+fn respond(response: &InteractionResponse) -> Response<ResponseBody> {
+    let body = serde_json::to_vec(response).expect("Could not serialize the interaction response");
+    Response::builder()
+        .header(CONTENT_TYPE, "application/json")
+        .body(Bytes::from(body).into())
+        .expect("Could not build the response")
+}
+
+fn error(status: StatusCode, message: &'static str) -> Response<ResponseBody> {
+    Response::builder()
+        .status(status)
+        .body(Bytes::from_static(message.as_bytes()).into())
+        .expect("Could not build the response")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use cordis_core::interactions::CallbackMessage;
+    use ed25519_dalek::{Signer, SigningKey};
+    use hyper::client::conn::http1 as client;
+    use rand::rngs::OsRng;
+    use serde_json::{json, Value};
+    use tokio::net::TcpStream;
+    use tokio::sync::oneshot;
+
+    use super::*;
+
+    const TIMESTAMP: &str = "1600000000";
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    fn command(name: &str) -> Value {
+        json!({
+            "id": "786008729715212338",
+            "application_id": "775799577604522054",
+            "type": 2,
+            "data": {"id": "771825006014889984", "name": name, "type": 1},
+            "channel_id": "645027906669510667",
+            "user": {"id": "53908232506183680", "username": "Mason", "discriminator": "1337", "avatar": null},
+            "token": "A_UNIQUE_TOKEN",
+            "version": 1
+        })
+    }
+
+    async fn start(key: &SigningKey, endpoint: impl FnOnce(InteractionEndpoint) -> InteractionEndpoint) -> SocketAddr {
+        let endpoint = endpoint(InteractionEndpoint::new(&hex(key.verifying_key().as_bytes())).unwrap());
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(endpoint.serve(listener));
+        address
+    }
+
+    async fn post(address: SocketAddr, signature: Option<String>, body: &Value) -> (StatusCode, Bytes) {
+        let body = body.to_string();
+        let stream = TcpStream::connect(address).await.unwrap();
+        let (mut sender, connection) = client::handshake(TokioIo::new(stream)).await.unwrap();
+        tokio::spawn(connection);
+        let mut request = Request::post("/").header(CONTENT_TYPE, "application/json");
+        if let Some(signature) = signature {
+            request = request.header(SIGNATURE_HEADER, signature).header(TIMESTAMP_HEADER, TIMESTAMP);
+        }
+        let response = sender.send_request(request.body(Full::new(Bytes::from(body))).unwrap()).await.unwrap();
+        let status = response.status();
+        (status, response.into_body().collect().await.unwrap().to_bytes())
+    }
+
+    async fn post_signed(address: SocketAddr, key: &SigningKey, body: &Value) -> (StatusCode, Value) {
+        let signature = hex(&key.sign(format!("{}{}", TIMESTAMP, body).as_bytes()).to_bytes());
+        let (status, body) = post(address, Some(signature), body).await;
+        (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+    }
+
+    #[tokio::test]
+    async fn answers_ping() {
+        let key = SigningKey::generate(&mut OsRng);
+        let address = start(&key, |endpoint| endpoint).await;
+        let ping = json!({"id": "1", "application_id": "2", "type": 1, "token": "t", "version": 1});
+        assert_eq!(post_signed(address, &key, &ping).await, (StatusCode::OK, json!({"type": 1})));
+    }
+
+    #[tokio::test]
+    async fn rejects_bad_signatures() {
+        let key = SigningKey::generate(&mut OsRng);
+        let address = start(&key, |endpoint| endpoint).await;
+        let ping = json!({"id": "1", "application_id": "2", "type": 1, "token": "t", "version": 1});
+        assert_eq!(post(address, None, &ping).await.0, StatusCode::UNAUTHORIZED);
+        let other = SigningKey::generate(&mut OsRng);
+        assert_eq!(post_signed(address, &other, &ping).await.0, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn routes_commands() {
+        let key = SigningKey::generate(&mut OsRng);
+        let address = start(&key, |endpoint| endpoint
+            .command("ping", |_| async {
+                InteractionResponse::message(CallbackMessage::new().content("Pong!")).into()
+            })
+            .command("whoami", |interaction| async move {
+                let name = interaction.author().map(|user| user.username.clone()).unwrap_or_default();
+                InteractionResponse::message(CallbackMessage::new().content(name).ephemeral(true)).into()
+            })
+        ).await;
+        assert_eq!(
+            post_signed(address, &key, &command("ping")).await,
+            (StatusCode::OK, json!({"type": 4, "data": {"content": "Pong!"}})),
+        );
+        assert_eq!(
+            post_signed(address, &key, &command("whoami")).await,
+            (StatusCode::OK, json!({"type": 4, "data": {"content": "Mason", "flags": 64}})),
+        );
+        assert_eq!(post_signed(address, &key, &command("unknown")).await.0, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn defers_and_follows_up() {
+        let key = SigningKey::generate(&mut OsRng);
+        let (sender, receiver) = oneshot::channel();
+        let sender = std::sync::Mutex::new(Some(sender));
+        let address = start(&key, move |endpoint| endpoint.command("slow", move |interaction| {
+            let sender = sender.lock().unwrap().take().unwrap();
+            async move {
+                Reply::defer(true, async move {
+                    sender.send(interaction.token).unwrap();
+                })
+            }
+        })).await;
+        assert_eq!(
+            post_signed(address, &key, &command("slow")).await,
+            (StatusCode::OK, json!({"type": 5, "data": {"flags": 64}})),
+        );
+        assert_eq!(receiver.await.unwrap(), "A_UNIQUE_TOKEN");
+    }
+
+    #[tokio::test]
+    async fn follows_up_after_the_response_is_written() {
+        let key = SigningKey::generate(&mut OsRng);
+        let (sender, mut receiver) = oneshot::channel();
+        let sender = std::sync::Mutex::new(Some(sender));
+        let endpoint = InteractionEndpoint::new(&hex(key.verifying_key().as_bytes())).unwrap()
+            .command("slow", move |_| {
+                let sender = sender.lock().unwrap().take().unwrap();
+                async move { Reply::defer(false, async move { sender.send(()).unwrap() }) }
+            });
+        let body = command("slow").to_string();
+        let signature = hex(&key.sign(format!("{}{}", TIMESTAMP, body).as_bytes()).to_bytes());
+        let request = Request::post("/")
+            .header(SIGNATURE_HEADER, signature)
+            .header(TIMESTAMP_HEADER, TIMESTAMP)
+            .body(Full::new(Bytes::from(body)))
+            .unwrap();
+        let response = endpoint.handle(request).await;
+        // The follow up would run here if it had been spawned before the response was handed back.
+        tokio::task::yield_now().await;
+        assert_eq!(receiver.try_recv(), Err(oneshot::error::TryRecvError::Empty));
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(serde_json::from_slice::<Value>(&body).unwrap(), json!({"type": 5}));
+        receiver.await.unwrap();
+    }
+}
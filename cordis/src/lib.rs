@@ -1,3 +1,8 @@
+#![warn(missing_docs)]
+//! A Discord framework built on the types of `cordis-core`.
+
+pub mod interactions;
+
 #[cfg(test)]
 mod tests {
     #[test]